@@ -2,7 +2,8 @@ use crate::ClippyConfiguration;
 use crate::types::{
     DisallowedPath, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename, SourceItemOrdering,
     SourceItemOrderingCategory, SourceItemOrderingModuleItemGroupings, SourceItemOrderingModuleItemKind,
-    SourceItemOrderingTraitAssocItemKind, SourceItemOrderingTraitAssocItemKinds,
+    SourceItemOrderingTraitAssocItemKind, SourceItemOrderingTraitAssocItemKinds, TransmuteConversion,
+    UnsafeBlockSuggestions, UnsafeOperationKind,
 };
 use clippy_utils::msrvs::Msrv;
 use rustc_errors::Applicability;
@@ -359,9 +360,41 @@ define_Conf! {
     /// default configuration of Clippy. By default, any configuration will replace the default value
     #[lints(module_name_repetitions)]
     allowed_prefixes: Vec<String> = DEFAULT_ALLOWED_PREFIXES.iter().map(ToString::to_string).collect(),
+    /// Paths of modules whose public functions may have raw pointers in their signatures. A trailing
+    /// `*` also matches all modules nested inside of a module, and a leading `crate` refers to the
+    /// current crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-raw-pointer-modules = [ "crate::sys", "crate::alloc::*" ]
+    /// ```
+    #[lints(raw_pointer_in_public_api)]
+    allowed_raw_pointer_modules: Vec<String> = Vec::new(),
     /// The list of unicode scripts allowed to be used in the scope.
     #[lints(disallowed_script_idents)]
     allowed_scripts: Vec<String> = vec!["Latin".to_string()],
+    /// Paths of modules whose `unsafe` blocks aren't checked. A trailing `*` also matches all modules
+    /// nested inside of a module, and a leading `crate` refers to the current crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-unsafe-block-modules = [ "crate::ffi", "crate::arch::*" ]
+    /// ```
+    #[lints(minimal_unsafe_block)]
+    allowed_unsafe_block_modules: Vec<String> = Vec::new(),
+    /// Paths of modules whose tests may contain `unsafe` blocks, such as modules of test helpers for
+    /// FFI. A trailing `*` also matches all modules nested inside of a module, and a leading `crate`
+    /// refers to the current crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// allowed-unsafe-test-modules = [ "crate::ffi::tests", "crate::sys::*" ]
+    /// ```
+    #[lints(unsafe_block_in_test)]
+    allowed_unsafe_test_modules: Vec<String> = Vec::new(),
     /// List of path segments allowed to have wildcard imports.
     ///
     /// #### Example
@@ -523,6 +556,10 @@ define_Conf! {
     /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
     #[lints(large_futures)]
     future_size_threshold: u64 = 16 * 1024,
+    /// The list of guard types, in addition to the lock guards of `std` and `parking_lot`, which
+    /// should not be forgotten.
+    #[lints(forget_guard)]
+    guard_types: Vec<DisallowedPath> = Vec::new(),
     /// A list of paths to types that should be treated as if they do not contain interior mutability
     #[lints(borrow_interior_mutable_const, declare_interior_mutable_const, ifs_same_cond, mutable_key_type)]
     ignore_interior_mutability: Vec<String> = Vec::from(["bytes::Bytes".into()]),
@@ -553,9 +590,111 @@ define_Conf! {
     /// The maximum number of bounds a trait can have to be linted
     #[lints(type_repetition_in_bounds)]
     max_trait_bounds: u64 = 3,
+    /// The maximum number of `unsafe` blocks that can be nested in each other, including blocks in
+    /// closures, or `0` for no limit. More deeply nested blocks are linted regardless of the unsafe
+    /// operations they contain.
+    #[lints(minimal_unsafe_block)]
+    max_unsafe_block_nesting: u64 = 0,
+    /// The maximum number of unsafe operations an `unsafe` block may contain, or `0` for no limit.
+    /// Blocks above the limit are linted, while blocks within it whose statements all perform
+    /// unsafe operations are accepted.
+    #[lints(minimal_unsafe_block)]
+    max_unsafe_operations_per_block: u64 = 0,
     /// Minimum chars an ident can have, anything below or equal to this will be linted.
     #[lints(min_ident_chars)]
     min_ident_chars_threshold: u64 = 1,
+    /// Whether to accept `unsafe` blocks covering several statements if they are preceded by a
+    /// `// SAFETY:` comment justifying them as a whole.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_accept_commented_blocks: bool = false,
+    /// Whether to accept `unsafe` blocks whose only statement is a `let` statement initialized by an
+    /// unsafe operation, optionally followed by the bound variable as the tail expression, as in
+    /// `unsafe { let x = ptr.read(); x }`.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_allow_let_wrapping: bool = false,
+    /// Whether to accept `unsafe` blocks covering several statements if every one of them performs
+    /// an unsafe operation.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_allow_unsafe_statements: bool = false,
+    /// Paths of functions that may be called from `unsafe` blocks of any size. Blocks whose
+    /// statements all call them, without any other unsafe operation, are accepted. A trailing `*`
+    /// matches every function in a module, and a leading `crate` refers to the current crate.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// minimal-unsafe-block-allowed-callees = [ "libc::*", "crate::sys::*" ]
+    /// ```
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_allowed_callees: Vec<String> = Vec::new(),
+    /// Whether to check `unsafe` blocks in generated code, i.e. in `#[automatically_derived]` items
+    /// and in files included from the `OUT_DIR` of a build script, such as `bindgen` output.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_check_generated_code: bool = true,
+    /// Whether to check `unsafe` blocks produced by macros defined in the current crate. Blocks
+    /// produced by macros from other crates are never checked.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_check_macros: bool = true,
+    /// Whether to check `unsafe` blocks in test functions or `#[cfg(test)]`
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_check_tests: bool = true,
+    /// Whether to check the bodies of `unsafe` functions as implicit `unsafe` blocks wherever the
+    /// `unsafe_op_in_unsafe_fn` lint is allowed, as it is by default before edition 2024.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_check_unsafe_fn_bodies: bool = false,
+    /// Whether calls to safe `#[target_feature]` functions from code without those target features
+    /// count as unsafe operations towards `max-unsafe-operations-per-block` and
+    /// `minimal-unsafe-block-require-single-op`. As they require an `unsafe` block, they are kept
+    /// inside of it either way.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_count_target_feature_calls: bool = true,
+    /// The kinds of unsafe operations that count for `minimal_unsafe_block`, out of
+    /// `"raw-pointer-deref"`, `"static-access"`, `"union-field-access"`, `"unsafe-call"` and
+    /// `"inline-asm"`. Blocks whose statements only perform operations of other kinds are accepted,
+    /// and those operations don't count towards `max-unsafe-operations-per-block`.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// # Allow blocks of MMIO register accesses
+    /// minimal-unsafe-block-counted-operations = [ "static-access", "union-field-access", "unsafe-call", "inline-asm" ]
+    /// ```
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_counted_operations: Vec<UnsafeOperationKind> = vec![
+        UnsafeOperationKind::RawPointerDeref,
+        UnsafeOperationKind::StaticAccess,
+        UnsafeOperationKind::UnionFieldAccess,
+        UnsafeOperationKind::UnsafeCall,
+        UnsafeOperationKind::InlineAsm,
+    ],
+    /// Whether to adapt the lint to the level of `unsafe_op_in_unsafe_fn` inside `unsafe` functions:
+    /// where the rustc lint warns about or denies unsafe operations, each `unsafe` block may contain
+    /// only a single unsafe operation, and where it's allowed, `unsafe` blocks aren't checked at all.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_follow_unsafe_op_in_unsafe_fn: bool = false,
+    /// Whether `minimal_unsafe_block` adds notes pointing at the unsafe operations of a block and at the
+    /// narrowest region covering all of them. The notes are meant for tools reading the JSON output of
+    /// `cargo clippy --message-format=json`, which can build their own code actions from these spans.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_region_notes: bool = false,
+    /// Whether to require every `unsafe` block to contain exactly one unsafe operation, even if all of
+    /// its statements need the `unsafe` context.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_require_single_op: bool = false,
+    /// Whether to lint `unsafe` blocks around a method chain with unsafe calls, as in
+    /// `unsafe { ptr.add(1).read().to_string() }`, until they only cover the unsafe part of the
+    /// chain. If disabled, one block around the whole chain is accepted.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_split_method_chains: bool = true,
+    /// Whether to also lint `unsafe` blocks around a single unsafe operation whose operands are
+    /// computed by safe code, as in `unsafe { f(compute()) }`.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_strict: bool = false,
+    /// Which suggestions to emit for `unsafe` blocks: `"all"` of them, only `"machine-applicable-only"`
+    /// ones that `cargo clippy --fix` applies, all of them as `"maybe-incorrect"` so that `--fix`
+    /// never touches `unsafe` code, or `"never"` any.
+    #[lints(minimal_unsafe_block)]
+    minimal_unsafe_block_suggestions: UnsafeBlockSuggestions = UnsafeBlockSuggestions::All,
     /// Whether to **only** check for missing documentation in items visible within the current
     /// crate. For example, `pub(crate)` items.
     #[lints(missing_docs_in_private_items)]
@@ -677,6 +816,21 @@ define_Conf! {
     /// The order of associated items in traits.
     #[lints(arbitrary_source_item_ordering)]
     trait_assoc_item_kinds_order: SourceItemOrderingTraitAssocItemKinds = DEFAULT_TRAIT_ASSOC_ITEM_KINDS_ORDER.into(),
+    /// Safe conversion functions to use instead of `transmute` between the given types. The types
+    /// are paths of ADTs, which match with any generic arguments, or other types written out like
+    /// `[u8; 4]`. Paths start with the name of their crate. An optional `reason` is added to the
+    /// diagnostic.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// transmute-conversions = [
+    ///     { from = "[u8; 4]", to = "my_crate::Header", replacement = "my_crate::Header::from_bytes" },
+    ///     { from = "my_crate::Header", to = "[u8; 4]", replacement = "my_crate::Header::to_bytes", reason = "checks the version" },
+    /// ]
+    /// ```
+    #[lints(transmute_with_safe_alternative)]
+    transmute_conversions: Vec<TransmuteConversion> = Vec::new(),
     /// The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by
     /// reference.
     #[default_text = "target_pointer_width * 2"]
@@ -691,6 +845,10 @@ define_Conf! {
     /// Should the fraction of a decimal be linted to include separators.
     #[lints(unreadable_literal)]
     unreadable_literal_lint_fractions: bool = true,
+    /// The maximum number of lines an `unsafe` block can span, or `0` for no limit. Larger blocks are
+    /// linted regardless of the unsafe operations they contain.
+    #[lints(minimal_unsafe_block)]
+    unsafe_block_max_lines: u64 = 0,
     /// Enables verbose mode. Triggers if there is more than one uppercase char next to each other
     #[lints(upper_case_acronyms)]
     upper_case_acronyms_aggressive: bool = false,
@@ -12,6 +12,14 @@ pub struct Rename {
     pub rename: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct TransmuteConversion {
+    pub from: String,
+    pub to: String,
+    pub replacement: String,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum DisallowedPath {
@@ -439,6 +447,7 @@ unimplemented_serialize! {
     DisallowedPath,
     Rename,
     MacroMatcher,
+    TransmuteConversion,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -446,3 +455,31 @@ pub enum PubUnderscoreFieldsBehaviour {
     PubliclyExported,
     AllPubFields,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnsafeOperationKind {
+    /// Dereferencing a raw pointer.
+    RawPointerDeref,
+    /// Accessing a mutable or `extern` static.
+    StaticAccess,
+    /// Accessing a field of a union.
+    UnionFieldAccess,
+    /// Calling an unsafe function or method.
+    UnsafeCall,
+    /// Using inline assembly.
+    InlineAsm,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnsafeBlockSuggestions {
+    /// Emit all suggestions with their own applicability.
+    All,
+    /// Emit only suggestions that can be applied automatically.
+    MachineApplicableOnly,
+    /// Emit all suggestions, but never let them be applied automatically.
+    MaybeIncorrect,
+    /// Emit no suggestions.
+    Never,
+}
@@ -0,0 +1,158 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{indent_of, snippet_with_applicability, walk_span_to_context};
+use clippy_utils::span_contains_comment;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, StmtKind, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+use std::fmt::Write as _;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for consecutive `unsafe` blocks without any code between them.
+    ///
+    /// ### Why is this bad?
+    /// Splitting a sequence of unsafe operations into one `unsafe` block per operation adds noise
+    /// without narrowing down the unsafe code, and the safety requirements of the operations are
+    /// often best explained together, in a single `SAFETY` comment.
+    ///
+    /// Blocks with a comment between them, such as a `SAFETY` comment of their own, aren't linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let ptr = [0u8; 2].as_mut_ptr();
+    /// unsafe { ptr.write(1) };
+    /// unsafe { ptr.add(1).write(2) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let ptr = [0u8; 2].as_mut_ptr();
+    /// unsafe {
+    ///     ptr.write(1);
+    ///     ptr.add(1).write(2);
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub ADJACENT_UNSAFE_BLOCKS,
+    pedantic,
+    "consecutive `unsafe` blocks which can be merged"
+}
+
+declare_lint_pass!(AdjacentUnsafeBlocks => [ADJACENT_UNSAFE_BLOCKS]);
+
+impl<'tcx> LateLintPass<'tcx> for AdjacentUnsafeBlocks {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        // The statements and the tail expression of the block, along with the `unsafe` blocks
+        // they consist of.
+        let units: Vec<(Span, Option<&Block<'_>>)> = block
+            .stmts
+            .iter()
+            .map(|stmt| match stmt.kind {
+                StmtKind::Expr(expr) | StmtKind::Semi(expr) => (stmt.span, mergeable_block(expr)),
+                StmtKind::Let(_) | StmtKind::Item(_) => (stmt.span, None),
+            })
+            .chain(block.expr.map(|tail| (tail.span, mergeable_block(tail))))
+            .collect();
+
+        let mut start = 0;
+        while start < units.len() {
+            let mut end = start + 1;
+            if let (span, Some(inner)) = units[start] {
+                let mut prev = (span, inner);
+                // Bindings of all but the last block would be dropped later after merging.
+                while let Some(&(span, Some(next))) = units.get(end)
+                    && !has_let(prev.1)
+                    && !span_contains_comment(cx.sess().source_map(), prev.0.between(span))
+                {
+                    prev = (span, next);
+                    end += 1;
+                }
+            }
+            if end - start > 1 {
+                let is_tail = end == units.len() && block.expr.is_some();
+                let run: Vec<_> = units[start..end]
+                    .iter()
+                    .filter_map(|&(span, block)| Some((span, block?)))
+                    .collect();
+                lint_run(cx, &run, is_tail);
+            }
+            start = end;
+        }
+    }
+}
+
+/// Returns the `unsafe` block of the expression, if it can be merged with other ones.
+fn mergeable_block<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Block<'tcx>> {
+    if let ExprKind::Block(block, None) = expr.kind
+        && block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+        && !expr.span.from_expansion()
+        && inner_span(block).is_some()
+    {
+        Some(block)
+    } else {
+        None
+    }
+}
+
+/// Returns the span from the first statement of the block to its last statement or tail
+/// expression.
+fn inner_span(block: &Block<'_>) -> Option<Span> {
+    let first = match (block.stmts.first(), block.expr) {
+        (Some(stmt), _) => stmt.span,
+        (None, Some(tail)) => tail.span,
+        (None, None) => return None,
+    };
+    let last = match (block.stmts.last(), block.expr) {
+        (_, Some(tail)) => tail.span,
+        (Some(stmt), None) => stmt.span,
+        (None, None) => return None,
+    };
+    let first = walk_span_to_context(first, block.span.ctxt())?;
+    let last = walk_span_to_context(last, block.span.ctxt())?;
+    Some(first.to(last))
+}
+
+/// Checks whether the block has `let` statements, whose bindings would live until the end of
+/// the merged block.
+fn has_let(block: &Block<'_>) -> bool {
+    block.stmts.iter().any(|stmt| matches!(stmt.kind, StmtKind::Let(_)))
+}
+
+/// Lints a run of consecutive `unsafe` blocks, given with the spans of their statements.
+fn lint_run(cx: &LateContext<'_>, run: &[(Span, &Block<'_>)], is_tail: bool) {
+    let span = run[0].0.to(run[run.len() - 1].0);
+    let indent = indent_of(cx, span).unwrap_or(0);
+    let mut app = Applicability::MachineApplicable;
+    let mut sugg = String::from("unsafe {\n");
+    for (i, &(_, block)) in run.iter().enumerate() {
+        let Some(inner) = inner_span(block) else {
+            continue;
+        };
+        if span_contains_comment(cx.sess().source_map(), block.span.until(inner))
+            || span_contains_comment(cx.sess().source_map(), inner.between(block.span.shrink_to_hi()))
+        {
+            app = Applicability::MaybeIncorrect;
+        }
+        let snip = snippet_with_applicability(cx, inner, "..", &mut app);
+        let _ = write!(sugg, "{}{snip}", " ".repeat(indent + 4));
+        if block.expr.is_some() && !(is_tail && i == run.len() - 1) {
+            sugg.push(';');
+        }
+        sugg.push('\n');
+    }
+    let _ = write!(sugg, "{}}}", " ".repeat(indent));
+
+    span_lint_and_sugg(
+        cx,
+        ADJACENT_UNSAFE_BLOCKS,
+        span,
+        "consecutive `unsafe` blocks",
+        "consider merging them into one `unsafe` block",
+        sugg,
+        app,
+    );
+}
@@ -1,9 +1,10 @@
 use std::fmt;
 
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
 use rustc_ast::ast::{Expr, ExprKind, InlineAsmOptions};
-use rustc_ast::{InlineAsm, Item, ItemKind};
+use rustc_ast::{AsmMacro, InlineAsm, Item, ItemKind};
 use rustc_lint::{EarlyContext, EarlyLintPass, Lint, LintContext};
+use rustc_middle::lint::in_external_macro;
 use rustc_session::declare_lint_pass;
 use rustc_span::Span;
 use rustc_target::asm::InlineAsmArch;
@@ -152,3 +153,67 @@ impl EarlyLintPass for InlineAsmX86AttSyntax {
         }
     }
 }
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `asm!` invocations which don't declare any of the options describing what the
+    /// assembly does, such as `nomem`, `readonly`, `nostack` or `preserves_flags`.
+    ///
+    /// ### Why restrict this?
+    /// Without options, the compiler has to assume that the assembly may read and write any memory,
+    /// push to the stack and clobber the flags, which pessimizes the code around it. The options
+    /// also document the assumptions the assembly makes.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    /// # unsafe { let x = 1u32;
+    /// # use std::arch::asm;
+    /// let y: u32;
+    /// asm!("lea {}, [{} + 1]", lateout(reg) y, in(reg) x);
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    /// # unsafe { let x = 1u32;
+    /// # use std::arch::asm;
+    /// let y: u32;
+    /// asm!("lea {}, [{} + 1]", lateout(reg) y, in(reg) x, options(pure, nomem, nostack, preserves_flags));
+    /// # }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub INLINE_ASM_WITHOUT_OPTIONS,
+    restriction,
+    "`asm!` invocations without options describing the assembly"
+}
+
+declare_lint_pass!(InlineAsmWithoutOptions => [INLINE_ASM_WITHOUT_OPTIONS]);
+
+/// The options which tell the compiler what the assembly does, unlike e.g. `att_syntax`.
+const SEMANTIC_OPTIONS: InlineAsmOptions = InlineAsmOptions::PURE
+    .union(InlineAsmOptions::NOMEM)
+    .union(InlineAsmOptions::READONLY)
+    .union(InlineAsmOptions::PRESERVES_FLAGS)
+    .union(InlineAsmOptions::NORETURN)
+    .union(InlineAsmOptions::NOSTACK);
+
+impl EarlyLintPass for InlineAsmWithoutOptions {
+    fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
+        if let ExprKind::InlineAsm(inline_asm) = &expr.kind
+            && matches!(inline_asm.asm_macro, AsmMacro::Asm)
+            && !inline_asm.options.intersects(SEMANTIC_OPTIONS)
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            span_lint_and_help(
+                cx,
+                INLINE_ASM_WITHOUT_OPTIONS,
+                expr.span,
+                "`asm!` without options describing the assembly",
+                None,
+                "consider declaring `nomem` or `readonly`, `nostack`, `preserves_flags` and such, where they apply",
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::{Descend, for_each_expr_without_closures};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, BlockCheckMode, ExprKind, LangItem, MatchSource, QPath, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe` blocks containing an `.await`.
+    ///
+    /// ### Why is this bad?
+    /// The task may be suspended at the `.await` for an arbitrary amount of time, while other code
+    /// runs. Raw pointers and the assumptions the unsafe operations of the block rely on, such as
+    /// the pointee staying valid and not being accessed elsewhere, are easily invalidated in the
+    /// meantime.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # async fn fetch() -> u8 { 0 }
+    /// async fn f(ptr: *mut u8) {
+    ///     unsafe {
+    ///         let value = fetch().await;
+    ///         ptr.write(value);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # async fn fetch() -> u8 { 0 }
+    /// async fn f(ptr: *mut u8) {
+    ///     let value = fetch().await;
+    ///     unsafe { ptr.write(value) };
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub AWAIT_IN_UNSAFE_BLOCK,
+    suspicious,
+    "`.await` inside an `unsafe` block"
+}
+
+declare_lint_pass!(AwaitInUnsafeBlock => [AWAIT_IN_UNSAFE_BLOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for AwaitInUnsafeBlock {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if !is_user_unsafe_block(block) || in_external_macro(cx.sess(), block.span) {
+            return;
+        }
+        // Closures and `async` blocks are suspended on their own, and the awaits of nested
+        // `unsafe` blocks are linted there.
+        let mut awaits = Vec::new();
+        for_each_expr_without_closures(block, |expr| match expr.kind {
+            ExprKind::Block(inner, _) if is_user_unsafe_block(inner) => ControlFlow::<(), _>::Continue(Descend::No),
+            ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar)
+                if let ExprKind::Call(func, [arg]) = scrutinee.kind
+                    && let ExprKind::Path(QPath::LangItem(LangItem::IntoFutureIntoFuture, ..)) = func.kind =>
+            {
+                // Only label the `.await` itself.
+                awaits.push(expr.span.with_lo(arg.span.hi()));
+                ControlFlow::Continue(Descend::Yes)
+            },
+            _ => ControlFlow::Continue(Descend::Yes),
+        });
+        if awaits.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            AWAIT_IN_UNSAFE_BLOCK,
+            block.span,
+            "`unsafe` block containing an `.await`",
+            |diag| {
+                for span in awaits {
+                    diag.span_label(span, "the task may be suspended here");
+                }
+                diag.help(
+                    "consider moving the `.await` out of the `unsafe` block, \
+                    so that no unsafe assumptions are held across it",
+                );
+            },
+        );
+    }
+}
+
+fn is_user_unsafe_block(block: &Block<'_>) -> bool {
+    block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+}
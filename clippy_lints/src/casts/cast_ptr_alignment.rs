@@ -1,5 +1,5 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::is_c_void;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{is_c_void, is_type_diagnostic_item};
 use clippy_utils::{get_parent_expr, is_hir_ty_cfg_dependant};
 use rustc_hir::{Expr, ExprKind, GenericArg};
 use rustc_lint::LateContext;
@@ -18,7 +18,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
             cx.typeck_results().expr_ty(cast_expr),
             cx.typeck_results().expr_ty(expr),
         );
-        lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+        lint_cast_ptr_alignment(cx, expr, cast_expr, cast_from, cast_to);
     } else if let ExprKind::MethodCall(method_path, self_arg, [], _) = &expr.kind {
         if method_path.ident.name.as_str() == "cast"
             && let Some(generic_args) = method_path.args
@@ -27,12 +27,18 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
             && !is_hir_ty_cfg_dependant(cx, cast_to)
         {
             let (cast_from, cast_to) = (cx.typeck_results().expr_ty(self_arg), cx.typeck_results().expr_ty(expr));
-            lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+            lint_cast_ptr_alignment(cx, expr, self_arg, cast_from, cast_to);
         }
     }
 }
 
-fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_from: Ty<'tcx>, cast_to: Ty<'tcx>) {
+fn lint_cast_ptr_alignment<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
     if let ty::RawPtr(from_ptr_ty, _) = *cast_from.kind()
         && let ty::RawPtr(to_ptr_ty, _) = *cast_to.kind()
         && let Ok(from_layout) = cx.layout_of(from_ptr_ty)
@@ -44,7 +50,7 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
         && !from_layout.is_zst()
         && !is_used_as_unaligned(cx, expr)
     {
-        span_lint(
+        span_lint_and_then(
             cx,
             CAST_PTR_ALIGNMENT,
             expr.span,
@@ -53,10 +59,34 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
                 from_layout.align.abi.bytes(),
                 to_layout.align.abi.bytes(),
             ),
+            |diag| {
+                diag.help(
+                    "if the pointer may be unaligned, access the pointee with `read_unaligned` and `write_unaligned`",
+                );
+                if is_slice_ptr(cx, cast_expr) {
+                    diag.help(format!(
+                        "to reinterpret the slice, consider using `align_to::<{to_ptr_ty}>`, which splits off the unaligned ends"
+                    ));
+                }
+                diag.help("otherwise, check the alignment of the pointer before dereferencing it");
+            },
         );
     }
 }
 
+/// Checks whether the expression is a call to `as_ptr` or `as_mut_ptr` on a slice, array or
+/// `Vec`.
+fn is_slice_ptr(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(name, self_arg, [], _) = e.kind
+        && matches!(name.ident.as_str(), "as_ptr" | "as_mut_ptr")
+    {
+        let ty = cx.typeck_results().expr_ty_adjusted(self_arg).peel_refs();
+        ty.is_slice() || ty.is_array() || is_type_diagnostic_item(cx, ty, sym::Vec)
+    } else {
+        false
+    }
+}
+
 fn is_used_as_unaligned(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     let Some(parent) = get_parent_expr(cx, e) else {
         return false;
@@ -234,6 +234,11 @@ declare_clippy_lint! {
     /// (&1u8 as *const u8).cast::<u16>();
     /// (&mut 1u8 as *mut u8).cast::<u16>();
     /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let bytes = [0u8; 2];
+    /// let _ = unsafe { bytes.as_ptr().cast::<u16>().read_unaligned() };
+    /// ```
     #[clippy::version = "pre 1.29.0"]
     pub CAST_PTR_ALIGNMENT,
     pedantic,
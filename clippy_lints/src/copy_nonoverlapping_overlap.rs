@@ -0,0 +1,250 @@
+use clippy_utils::consts::{ConstEvalCtxt, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{eq_expr_value, expr_or_init, path_def_id, path_to_local, peel_ref_operators, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `ptr::copy_nonoverlapping`, `copy_to_nonoverlapping` and
+    /// `copy_from_nonoverlapping` where the source and the destination point into the same
+    /// buffer, at offsets which are closer together than the number of copied elements.
+    ///
+    /// ### Why is this bad?
+    /// The source and the destination must not overlap, which is undefined behavior otherwise.
+    /// `ptr::copy` supports overlapping memory.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut buf = [0u8; 8];
+    /// let ptr = buf.as_mut_ptr();
+    /// unsafe { std::ptr::copy_nonoverlapping(ptr, ptr.add(1), 4) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut buf = [0u8; 8];
+    /// let ptr = buf.as_mut_ptr();
+    /// unsafe { std::ptr::copy(ptr, ptr.add(1), 4) };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub COPY_NONOVERLAPPING_OVERLAP,
+    suspicious,
+    "`copy_nonoverlapping` with a source and a destination which may overlap"
+}
+
+declare_lint_pass!(CopyNonoverlappingOverlap => [COPY_NONOVERLAPPING_OVERLAP]);
+
+impl<'tcx> LateLintPass<'tcx> for CopyNonoverlappingOverlap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && let Some(call) = CopyCall::new(cx, expr)
+            && let Some(src) = PtrOffset::new(cx, call.elem_ty, call.src)
+            && let Some(dst) = PtrOffset::new(cx, call.elem_ty, call.dst)
+            && let Some(distance) = src.distance(cx, &dst)
+        {
+            let count = ConstEvalCtxt::new(cx)
+                .eval(call.count)
+                .and_then(|count| count.int_value(cx.tcx, cx.typeck_results().expr_ty(call.count)));
+            let msg = match count {
+                Some(FullInt::U(count)) if count <= distance => return,
+                _ if distance == 0 => "the same source and destination",
+                Some(_) => "an overlapping source and destination",
+                None => "a source and a destination which may overlap",
+            };
+
+            span_lint_and_then(
+                cx,
+                COPY_NONOVERLAPPING_OVERLAP,
+                expr.span,
+                format!("`{}` with {msg}", call.name),
+                |diag| {
+                    if distance != 0 {
+                        diag.note(format!(
+                            "the source and the destination are {distance} element{} apart",
+                            if distance == 1 { "" } else { "s" }
+                        ));
+                    }
+                    diag.span_suggestion(
+                        call.sugg_span,
+                        format!("use `{}`, which supports overlapping memory", call.overlapping_name),
+                        call.sugg,
+                        Applicability::MaybeIncorrect,
+                    );
+                    if count.is_none() && distance != 0 {
+                        diag.help(format!(
+                            "otherwise, if the number of elements is never more than {distance}, consider asserting it"
+                        ));
+                    }
+                },
+            );
+        }
+    }
+}
+
+struct CopyCall<'tcx> {
+    name: &'static str,
+    overlapping_name: &'static str,
+    /// The span of the function name and its replacement.
+    sugg_span: Span,
+    sugg: String,
+    elem_ty: Ty<'tcx>,
+    src: &'tcx Expr<'tcx>,
+    dst: &'tcx Expr<'tcx>,
+    count: &'tcx Expr<'tcx>,
+}
+
+impl<'tcx> CopyCall<'tcx> {
+    fn new(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        match expr.kind {
+            ExprKind::Call(func, [src, dst, count])
+                if let Some(fn_id) = path_def_id(cx, func)
+                    && cx.tcx.is_diagnostic_item(sym::ptr_copy_nonoverlapping, fn_id)
+                    && let ExprKind::Path(QPath::Resolved(_, path)) = func.kind =>
+            {
+                // `copy` may not be imported
+                let (sugg_span, sugg) = if let [.., segment] = path.segments
+                    && path.segments.len() > 1
+                {
+                    (segment.ident.span, "copy".to_owned())
+                } else {
+                    (func.span, format!("{}::ptr::copy", std_or_core(cx)?))
+                };
+                Some(Self {
+                    name: "copy_nonoverlapping",
+                    overlapping_name: "copy",
+                    sugg_span,
+                    sugg,
+                    elem_ty: cx.typeck_results().node_args(func.hir_id).type_at(0),
+                    src,
+                    dst,
+                    count,
+                })
+            },
+            ExprKind::MethodCall(path, recv, [arg, count], _)
+                if let ty::RawPtr(elem_ty, _) = *cx.typeck_results().expr_ty(recv).kind() =>
+            {
+                let (name, overlapping_name, src, dst) = match path.ident.as_str() {
+                    "copy_to_nonoverlapping" => ("copy_to_nonoverlapping", "copy_to", recv, arg),
+                    "copy_from_nonoverlapping" => ("copy_from_nonoverlapping", "copy_from", arg, recv),
+                    _ => return None,
+                };
+                Some(Self {
+                    name,
+                    overlapping_name,
+                    sugg_span: path.ident.span,
+                    sugg: overlapping_name.to_owned(),
+                    elem_ty,
+                    src,
+                    dst,
+                    count,
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A pointer into a local buffer, at an offset in elements of the copied type.
+struct PtrOffset<'tcx> {
+    base: HirId,
+    constant: i128,
+    /// The non-constant parts of the offset, and whether they're subtracted.
+    terms: Vec<(&'tcx Expr<'tcx>, bool)>,
+}
+
+impl<'tcx> PtrOffset<'tcx> {
+    fn new(cx: &LateContext<'tcx>, elem_ty: Ty<'tcx>, mut ptr: &'tcx Expr<'tcx>) -> Option<Self> {
+        let mut offset = Self {
+            base: HirId::INVALID,
+            constant: 0,
+            terms: Vec::new(),
+        };
+        loop {
+            ptr = expr_or_init(cx, ptr);
+            match ptr.kind {
+                ExprKind::Cast(inner, _) => ptr = inner,
+                ExprKind::MethodCall(path, recv, [], _) => match path.ident.as_str() {
+                    "cast" | "cast_mut" | "cast_const" => ptr = recv,
+                    "as_ptr" | "as_mut_ptr" => {
+                        offset.base = path_to_local(peel_ref_operators(cx, recv))?;
+                        return Some(offset);
+                    },
+                    _ => return None,
+                },
+                ExprKind::MethodCall(path, recv, [count], _) => {
+                    let negated = match path.ident.as_str() {
+                        "add" | "wrapping_add" | "offset" | "wrapping_offset" => false,
+                        "sub" | "wrapping_sub" => true,
+                        _ => return None,
+                    };
+                    // The offset must be in elements of the copied type
+                    if !matches!(*cx.typeck_results().expr_ty(recv).kind(), ty::RawPtr(ty, _) if ty == elem_ty) {
+                        return None;
+                    }
+                    offset.add(cx, count, negated)?;
+                    ptr = recv;
+                },
+                ExprKind::AddrOf(_, _, inner) => {
+                    offset.base = path_to_local(inner)?;
+                    return Some(offset);
+                },
+                _ => {
+                    offset.base = path_to_local(ptr)?;
+                    return Some(offset);
+                },
+            }
+        }
+    }
+
+    fn add(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, negated: bool) -> Option<()> {
+        match expr.kind {
+            ExprKind::Binary(op, left, right) if op.node == BinOpKind::Add => {
+                self.add(cx, left, negated)?;
+                self.add(cx, right, negated)
+            },
+            ExprKind::Binary(op, left, right) if op.node == BinOpKind::Sub => {
+                self.add(cx, left, negated)?;
+                self.add(cx, right, !negated)
+            },
+            _ => {
+                if let Some(value) = ConstEvalCtxt::new(cx)
+                    .eval(expr)
+                    .and_then(|value| value.int_value(cx.tcx, cx.typeck_results().expr_ty(expr)))
+                {
+                    let value = match value {
+                        FullInt::S(value) => value,
+                        FullInt::U(value) => i128::try_from(value).ok()?,
+                    };
+                    self.constant = if negated {
+                        self.constant.checked_sub(value)?
+                    } else {
+                        self.constant.checked_add(value)?
+                    };
+                } else {
+                    self.terms.push((expr, negated));
+                }
+                Some(())
+            },
+        }
+    }
+
+    /// Returns the number of elements between both pointers, if they point into the same buffer at
+    /// a known distance.
+    fn distance(&self, cx: &LateContext<'_>, other: &Self) -> Option<u128> {
+        if self.base != other.base || self.terms.len() != other.terms.len() {
+            return None;
+        }
+        let mut other_terms = other.terms.clone();
+        for &(term, negated) in &self.terms {
+            let pos = other_terms.iter().position(|&(other_term, other_negated)| {
+                negated == other_negated && eq_expr_value(cx, term, other_term)
+            })?;
+            other_terms.swap_remove(pos);
+        }
+        Some(self.constant.checked_sub(other.constant)?.unsigned_abs())
+    }
+}
@@ -0,0 +1,121 @@
+use clippy_config::Conf;
+use clippy_config::types::create_disallowed_map;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{match_def_path, paths};
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `mem::forget` and `ManuallyDrop::new` with a lock guard, such as
+    /// `MutexGuard`, `RwLockReadGuard` or `RefMut`, or with one of the types listed in the
+    /// `guard-types` configuration.
+    ///
+    /// ### Why is this bad?
+    /// A forgotten guard is never released, so the lock stays locked, and every later attempt to
+    /// lock it blocks or fails. This is rarely intended, and is easy to miss when reading the code.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// let mutex = Mutex::new(0);
+    /// let mut guard = mutex.lock().unwrap();
+    /// *guard += 1;
+    /// std::mem::forget(guard);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// let mutex = Mutex::new(0);
+    /// let mut guard = mutex.lock().unwrap();
+    /// *guard += 1;
+    /// drop(guard);
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub FORGET_GUARD,
+    suspicious,
+    "forgetting a lock guard, which keeps the lock locked"
+}
+
+impl_lint_pass!(ForgetGuard => [FORGET_GUARD]);
+
+pub struct ForgetGuard {
+    def_ids: DefIdMap<(&'static str, Option<&'static str>)>,
+}
+
+impl ForgetGuard {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            def_ids: create_disallowed_map(tcx, &conf.guard_types),
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ForgetGuard {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(callee, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let ty::FnDef(fn_id, _) = *cx.typeck_results().expr_ty(callee).kind()
+            && let Some(fn_name) = forgetting_fn_name(cx, fn_id)
+            && let arg_ty = cx.typeck_results().expr_ty(arg)
+            && let ty::Adt(adt, _) = arg_ty.kind()
+        {
+            let reason = if is_lock_guard(cx, adt.did()) {
+                None
+            } else if let Some(&(_, reason)) = self.def_ids.get(&adt.did()) {
+                reason
+            } else {
+                return;
+            };
+
+            span_lint_and_then(
+                cx,
+                FORGET_GUARD,
+                expr.span,
+                format!("`{fn_name}` on a guard of type `{arg_ty}`"),
+                |diag| {
+                    if let Some(reason) = reason {
+                        diag.note(reason);
+                    }
+                    diag.note("the guard is never released");
+                    diag.help("if the guard should be released here, use `drop` instead");
+                    diag.help(
+                        "otherwise, consider moving the guard into a block which ends where it should be released",
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the name of the function if it's `mem::forget` or `ManuallyDrop::new`.
+fn forgetting_fn_name(cx: &LateContext<'_>, fn_id: DefId) -> Option<&'static str> {
+    if cx.tcx.is_diagnostic_item(sym::mem_forget, fn_id) {
+        Some("mem::forget")
+    } else if cx.tcx.item_name(fn_id) == sym::new
+        && let Some(impl_id) = cx.tcx.impl_of_method(fn_id)
+        && is_type_lang_item(
+            cx,
+            cx.tcx.type_of(impl_id).instantiate_identity(),
+            LangItem::ManuallyDrop,
+        )
+    {
+        Some("ManuallyDrop::new")
+    } else {
+        None
+    }
+}
+
+fn is_lock_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    matches!(
+        cx.tcx.get_diagnostic_name(def_id),
+        Some(sym::MutexGuard | sym::RwLockReadGuard | sym::RwLockWriteGuard | sym::RefCellRef | sym::RefCellRefMut)
+    ) || match_def_path(cx, def_id, &paths::PARKING_LOT_MUTEX_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_READ_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_WRITE_GUARD)
+}
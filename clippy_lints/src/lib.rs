@@ -74,6 +74,7 @@ pub mod deprecated_lints;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
 mod absolute_paths;
+mod adjacent_unsafe_blocks;
 mod almost_complete_range;
 mod approx_const;
 mod arbitrary_source_item_ordering;
@@ -86,6 +87,7 @@ mod assigning_clones;
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
+mod await_in_unsafe_block;
 mod blocks_in_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
@@ -103,6 +105,7 @@ mod collection_is_never_read;
 mod comparison_chain;
 mod copies;
 mod copy_iterator;
+mod copy_nonoverlapping_overlap;
 mod crate_in_macro_def;
 mod create_dir;
 mod dbg_macro;
@@ -144,6 +147,7 @@ mod fallible_impl_from;
 mod field_scoped_visibility_modifiers;
 mod float_literal;
 mod floating_point_arithmetic;
+mod forget_guard;
 mod format;
 mod format_args;
 mod format_impl;
@@ -231,9 +235,11 @@ mod matches;
 mod mem_replace;
 mod methods;
 mod min_ident_chars;
+mod minimal_unsafe_block;
 mod minmax;
 mod misc;
 mod misc_early;
+mod mismatched_alloc_layout;
 mod mismatching_type_param_order;
 mod missing_assert_message;
 mod missing_asserts_for_indexing;
@@ -286,25 +292,30 @@ mod only_used_in_recursion;
 mod operators;
 mod option_env_unwrap;
 mod option_if_let_else;
+mod panic_in_extern_c_fn;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod panicking_overflow_checks;
 mod partial_pub_fields;
 mod partialeq_ne_impl;
 mod partialeq_to_none;
+mod partially_initialized_assume_init;
 mod pass_by_ref_or_value;
 mod pathbuf_init_then_push;
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
+mod pinned_local_moved;
 mod pointers_in_nomem_asm_block;
 mod precedence;
 mod ptr;
 mod ptr_offset_with_cast;
+mod ptr_to_int_in_const_fn;
 mod pub_underscore_fields;
 mod pub_use;
 mod question_mark;
 mod question_mark_used;
 mod ranges;
+mod raw_pointer_in_public_api;
 mod raw_strings;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
@@ -314,6 +325,7 @@ mod redundant_closure_call;
 mod redundant_else;
 mod redundant_field_names;
 mod redundant_locals;
+mod redundant_nested_unsafe_block;
 mod redundant_pub_crate;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
@@ -330,6 +342,7 @@ mod same_name_method;
 mod self_named_constructors;
 mod semicolon_block;
 mod semicolon_if_nothing_returned;
+mod send_sync_impl_without_bounds;
 mod serde_api;
 mod set_contains_or_insert;
 mod shadow;
@@ -341,6 +354,7 @@ mod single_range_in_vec_init;
 mod size_of_in_element_count;
 mod size_of_ref;
 mod slow_vector_initialization;
+mod static_mut;
 mod std_instead_of_core;
 mod string_patterns;
 mod strings;
@@ -360,8 +374,11 @@ mod trait_bounds;
 mod transmute;
 mod tuple_array_conversions;
 mod types;
+mod unchecked_constructors;
 mod unconditional_recursion;
+mod undocumented_safe_wrapper;
 mod undocumented_unsafe_blocks;
+mod unguarded_get_unchecked;
 mod unicode;
 mod uninhabited_references;
 mod uninit_vec;
@@ -375,6 +392,7 @@ mod unnecessary_self_imports;
 mod unnecessary_struct_initialization;
 mod unnecessary_wraps;
 mod unnested_or_patterns;
+mod unsafe_block_in_test;
 mod unsafe_removed_from_name;
 mod unused_async;
 mod unused_io_amount;
@@ -686,7 +704,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(borrow_deref_ref::BorrowDerefRef));
     store.register_late_pass(|_| Box::<no_effect::NoEffect>::default());
     store.register_late_pass(|_| Box::new(temporary_assignment::TemporaryAssignment));
-    store.register_late_pass(move |_| Box::new(transmute::Transmute::new(conf)));
+    store.register_late_pass(move |tcx| Box::new(transmute::Transmute::new(tcx, conf)));
     store.register_late_pass(move |_| Box::new(cognitive_complexity::CognitiveComplexity::new(conf)));
     store.register_late_pass(move |_| Box::new(escape::BoxedLocal::new(conf)));
     store.register_late_pass(move |_| Box::new(vec::UselessVec::new(conf)));
@@ -810,6 +828,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |tcx| Box::new(disallowed_methods::DisallowedMethods::new(tcx, conf)));
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86AttSyntax));
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86IntelSyntax));
+    store.register_early_pass(|| Box::new(asm_syntax::InlineAsmWithoutOptions));
     store.register_late_pass(|_| Box::new(empty_drop::EmptyDrop));
     store.register_late_pass(|_| Box::new(strings::StrToString));
     store.register_late_pass(|_| Box::new(strings::StringToString));
@@ -966,5 +985,23 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(manual_ignore_case_cmp::ManualIgnoreCaseCmp));
     store.register_late_pass(|_| Box::new(unnecessary_literal_bound::UnnecessaryLiteralBound));
     store.register_late_pass(move |_| Box::new(arbitrary_source_item_ordering::ArbitrarySourceItemOrdering::new(conf)));
+    store.register_late_pass(move |_| Box::new(minimal_unsafe_block::MinimalUnsafeBlock::new(conf)));
+    store.register_late_pass(|_| Box::new(redundant_nested_unsafe_block::RedundantNestedUnsafeBlock));
+    store.register_late_pass(|_| Box::new(adjacent_unsafe_blocks::AdjacentUnsafeBlocks));
+    store.register_late_pass(|_| Box::new(await_in_unsafe_block::AwaitInUnsafeBlock));
+    store.register_late_pass(|_| Box::new(undocumented_safe_wrapper::UndocumentedSafeWrapper));
+    store.register_late_pass(|_| Box::new(static_mut::StaticMut));
+    store.register_late_pass(|_| Box::new(unguarded_get_unchecked::UnguardedGetUnchecked));
+    store.register_late_pass(|_| Box::new(send_sync_impl_without_bounds::SendSyncImplWithoutBounds));
+    store.register_late_pass(move |tcx| Box::new(forget_guard::ForgetGuard::new(tcx, conf)));
+    store.register_late_pass(|_| Box::new(unchecked_constructors::UncheckedConstructors));
+    store.register_late_pass(|_| Box::new(mismatched_alloc_layout::MismatchedAllocLayout));
+    store.register_late_pass(|_| Box::new(copy_nonoverlapping_overlap::CopyNonoverlappingOverlap));
+    store.register_late_pass(|_| Box::new(partially_initialized_assume_init::PartiallyInitializedAssumeInit));
+    store.register_late_pass(|_| Box::new(pinned_local_moved::PinnedLocalMoved));
+    store.register_late_pass(move |_| Box::new(unsafe_block_in_test::UnsafeBlockInTest::new(conf)));
+    store.register_late_pass(|_| Box::new(panic_in_extern_c_fn::PanicInExternCFn));
+    store.register_late_pass(move |_| Box::new(raw_pointer_in_public_api::RawPointerInPublicApi::new(conf)));
+    store.register_late_pass(|_| Box::new(ptr_to_int_in_const_fn::PtrToIntInConstFn));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
@@ -23,6 +23,7 @@ mod significant_drop_in_scrutinee;
 mod single_match;
 mod try_err;
 mod wild_in_or_pats;
+mod wildcard_unreachable_unchecked;
 
 use clippy_config::Conf;
 use clippy_utils::msrvs::{self, Msrv};
@@ -977,6 +978,41 @@ declare_clippy_lint! {
     "checks for unnecessary guards in match expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unreachable_unchecked()` in the wildcard arm of a `match` on a
+    /// `#[non_exhaustive]` enum from another crate.
+    ///
+    /// ### Why is this bad?
+    /// The wildcard arm is reached by any variant added to the enum in a later version of its
+    /// crate, which is undefined behavior. `unreachable!()` panics instead, at a negligible cost.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::io::ErrorKind;
+    /// fn describe(kind: ErrorKind) -> &'static str {
+    ///     match kind {
+    ///         ErrorKind::NotFound => "not found",
+    ///         _ => unsafe { std::hint::unreachable_unchecked() },
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::io::ErrorKind;
+    /// fn describe(kind: ErrorKind) -> &'static str {
+    ///     match kind {
+    ///         ErrorKind::NotFound => "not found",
+    ///         _ => unreachable!(),
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub WILDCARD_UNREACHABLE_UNCHECKED,
+    suspicious,
+    "`unreachable_unchecked()` in the wildcard arm of a match on a non-exhaustive enum"
+}
+
 pub struct Matches {
     msrv: Msrv,
     infallible_destructuring_match_linted: bool,
@@ -1018,6 +1054,7 @@ impl_lint_pass!(Matches => [
     MANUAL_MAP,
     MANUAL_FILTER,
     REDUNDANT_GUARDS,
+    WILDCARD_UNREACHABLE_UNCHECKED,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1048,6 +1085,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                 // These don't depend on a relationship between multiple arms
                 match_wild_err_arm::check(cx, ex, arms);
                 wild_in_or_pats::check(cx, ex, arms);
+                wildcard_unreachable_unchecked::check(cx, ex, arms);
             }
 
             if let MatchSource::TryDesugar(_) = source {
@@ -0,0 +1,73 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_wild, match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Block, Expr, ExprKind, PatKind, Stmt, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::WILDCARD_UNREACHABLE_UNCHECKED;
+
+pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &Expr<'tcx>, arms: &[Arm<'tcx>]) {
+    let ex_ty = cx.typeck_results().expr_ty(ex).peel_refs();
+    if let ty::Adt(adt, _) = ex_ty.kind()
+        && adt.is_enum()
+        && adt.is_variant_list_non_exhaustive()
+        && !adt.did().is_local()
+        && let Some(arm) = arms
+            .iter()
+            .find(|arm| arm.guard.is_none() && (is_wild(arm.pat) || matches!(arm.pat.kind, PatKind::Binding(.., None))))
+        && is_unreachable_unchecked(cx, arm.body)
+    {
+        span_lint_and_then(
+            cx,
+            WILDCARD_UNREACHABLE_UNCHECKED,
+            arm.body.span,
+            format!("`unreachable_unchecked()` in the wildcard arm of a match on the non-exhaustive `{ex_ty}`"),
+            |diag| {
+                diag.span_note(
+                    arm.pat.span,
+                    format!("this arm is reached by any variant added to `{ex_ty}`, which is undefined behavior"),
+                );
+                diag.span_suggestion(
+                    arm.body.span,
+                    "use `unreachable!()` instead",
+                    "unreachable!()",
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+    }
+}
+
+/// Checks if the expression only calls `unreachable_unchecked`, possibly in (`unsafe`) blocks.
+fn is_unreachable_unchecked(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> bool {
+    while let ExprKind::Block(
+        Block {
+            stmts: [],
+            expr: Some(inner),
+            ..
+        }
+        | Block {
+            stmts:
+                [
+                    Stmt {
+                        kind: StmtKind::Expr(inner) | StmtKind::Semi(inner),
+                        ..
+                    },
+                ],
+            expr: None,
+            ..
+        },
+        _,
+    ) = expr.kind
+    {
+        expr = inner;
+    }
+    if let ExprKind::Call(func, []) = expr.kind
+        && let Some(def_id) = path_def_id(cx, func)
+    {
+        match_def_path(cx, def_id, &paths::HINT_UNREACHABLE_UNCHECKED)
+    } else {
+        false
+    }
+}
@@ -0,0 +1,2543 @@
+use crate::undocumented_unsafe_blocks::{
+    UNDOCUMENTED_UNSAFE_BLOCKS, block_has_safety_comment, block_safety_comment_start,
+};
+use clippy_config::Conf;
+use clippy_config::types::{UnsafeBlockSuggestions, UnsafeOperationKind};
+use clippy_utils::attrs::has_safety_section;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::{
+    first_line_of_span, indent_of, reindent_multiline, snippet, snippet_indent, snippet_opt,
+    snippet_with_applicability, snippet_with_context, unsafe_keyword_span, walk_span_to_context,
+};
+use clippy_utils::ty::needs_ordered_drop;
+use clippy_utils::visitors::{
+    Descend, Visitable, any_temporaries_need_ordered_drop, contains_unsafe_op, for_each_expr,
+    for_each_expr_without_closures, is_local_used, is_safe_target_feature_call, is_unsafe_op,
+};
+use clippy_utils::{
+    in_automatically_derived, is_in_test, is_lint_allowed, is_trait_impl_item, matches_any_path, path_to_local,
+    span_contains_comment,
+};
+use core::mem;
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::{Applicability, Diag, DiagMessage, MultiSpan, Suggestions};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    BinOpKind, Block, BlockCheckMode, Body, Expr, ExprKind, FnDecl, HirId, LetStmt, MatchSource, Node, PatKind, Safety,
+    Stmt, StmtKind, UnOp, UnsafeSource,
+};
+use rustc_lexer::{TokenKind, tokenize};
+use rustc_lint::builtin::{UNSAFE_OP_IN_UNSAFE_FN, UNUSED_UNSAFE};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, GenericParamDefKind};
+use rustc_session::impl_lint_pass;
+use rustc_span::symbol::kw;
+use rustc_span::{BytePos, DesugaringKind, FileName, Pos, Span, SyntaxContext};
+use std::path::PathBuf;
+use std::{env, fs};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe` blocks that cover more code than the unsafe operations they contain,
+    /// e.g. blocks containing statements or blocks whose value is computed by safe code around an
+    /// unsafe operation.
+    ///
+    /// ### Why restrict this?
+    /// Everything inside an `unsafe` block has to be audited when reviewing the block. Keeping the
+    /// block as small as possible makes it obvious which operation actually requires `unsafe` and
+    /// lets the compiler check the surrounding code as usual.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # unsafe fn f(_: u32) -> u32 { 0 }
+    /// let x = unsafe {
+    ///     let y = 1;
+    ///     f(y)
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # unsafe fn f(_: u32) -> u32 { 0 }
+    /// let y = 1;
+    /// let x = unsafe { f(y) };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub MINIMAL_UNSAFE_BLOCK,
+    restriction,
+    "`unsafe` block covering more than the unsafe operations it contains"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe` functions whose bodies consist mostly of safe code, and which don't
+    /// document their safety requirements in a `# Safety` section.
+    ///
+    /// ### Why restrict this?
+    /// Making a function `unsafe` only because it performs a few unsafe operations makes every
+    /// call of it `unsafe` as well, and hides the few operations that need care among safe code.
+    /// If callers don't have to uphold any safety requirements, the function can be safe, with
+    /// small `unsafe` blocks around its unsafe operations. Otherwise, the requirements should be
+    /// documented.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// unsafe fn sum_first(v: &[u32]) -> u32 {
+    ///     let mut sum = 0;
+    ///     for x in v {
+    ///         sum += x;
+    ///     }
+    ///     let first = *v.get_unchecked(0);
+    ///     sum + first
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn sum_first(v: &[u32]) -> u32 {
+    ///     let mut sum = 0;
+    ///     for x in v {
+    ///         sum += x;
+    ///     }
+    ///     let first = v[0];
+    ///     sum + first
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub MOSTLY_SAFE_UNSAFE_FN,
+    restriction,
+    "`unsafe` function whose body consists mostly of safe code"
+}
+
+/// The maximum number of unsafe operations that are listed individually in a diagnostic.
+const MAX_LISTED_OPS: usize = 3;
+
+/// The minimum number of statements in the body of an `unsafe` function for
+/// `mostly_safe_unsafe_fn` to lint it.
+const MIN_MOSTLY_SAFE_FN_STMTS: usize = 4;
+
+/// The maximum length of the narrow form of an expression shown as an example in a help message.
+const MAX_FORM_LEN: usize = 80;
+
+/// How often an unsafe function has to be called in a block for extracting it into a safe wrapper
+/// function to be suggested.
+const REPEATED_CALLS: usize = 3;
+
+/// Whole function bodies with more statements than this are only rewritten with a
+/// `MaybeIncorrect` suggestion, as the result is more likely to need some manual touch-ups.
+const LARGE_BODY_STMTS: usize = 10;
+
+/// Why an `unsafe` block is linted, which determines the message and help of its diagnostic.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Reason {
+    /// The block is the whole body of a safe function.
+    WholeFnBody,
+    /// The block has safe statements before a tail expression with the unsafe operations.
+    SafeStmtsBeforeTail,
+    /// The block consists of a single `let _ = ..` statement.
+    WholeDiscard,
+    /// The block has statements which can be moved out of it. Statements expanded from the same
+    /// macro call count as one, and so does the tail expression.
+    CoversStmts { stmts: usize, ops: usize },
+    /// The block only consists of another `unsafe` block.
+    WrapsUnsafeBlock,
+    /// The tail expression of the block is safe code around the unsafe operations.
+    SafeTail(TailKind),
+    /// The operands of the sole unsafe operation of the block are computed inside of it.
+    SafeOperands,
+    /// The body of an `unsafe` function covers safe code without any explicit `unsafe` block.
+    UnsafeFnBody,
+    /// The block contains more unsafe operations than configured.
+    TooManyOps { ops: usize, max: usize },
+    /// The block spans more lines than configured.
+    TooManyLines { lines: usize, max: usize },
+    /// The block is nested in more `unsafe` blocks than configured.
+    NestedTooDeeply { depth: usize, max: usize },
+}
+
+impl Reason {
+    /// Returns the message of the diagnostic, which calls the block a block of FFI calls if `ffi`
+    /// is set.
+    fn msg(self, ffi: bool) -> String {
+        let block = if ffi {
+            "this `unsafe` block of FFI calls"
+        } else {
+            "this `unsafe` block"
+        };
+        match self {
+            Self::WholeFnBody => format!("{block} covers the whole function body"),
+            Self::SafeStmtsBeforeTail => format!("{block} covers safe statements before its tail expression"),
+            Self::WholeDiscard => format!("{block} covers a whole `let _ = ..` statement"),
+            Self::CoversStmts { stmts, ops } if ops < stmts => format!(
+                "{block} covers {stmts} statements but only {ops} unsafe operation{}",
+                if ops == 1 { "" } else { "s" }
+            ),
+            Self::CoversStmts { .. } => format!("{block} covers statements"),
+            Self::WrapsUnsafeBlock => format!("{block} only wraps another `unsafe` block"),
+            Self::SafeTail(kind) => format!("{block} covers {}", kind.describe()),
+            Self::SafeOperands => format!("{block} covers the computation of safe operands"),
+            Self::UnsafeFnBody => {
+                "the body of this `unsafe` function is an implicit `unsafe` block covering safe code".into()
+            },
+            Self::TooManyOps { ops, .. } => format!("{block} contains {ops} unsafe operations"),
+            Self::TooManyLines { lines, max } => format!("{block} spans too many lines ({lines}/{max})"),
+            Self::NestedTooDeeply { depth, max } => format!("{block} is nested too deeply ({depth}/{max})"),
+        }
+    }
+
+    /// Adds the help message of the reason, for reasons whose diagnostics don't always come with
+    /// a suggestion.
+    fn add_help(self, diag: &mut Diag<'_, ()>) {
+        if let Some(help) = self.help() {
+            diag.help(help);
+        }
+    }
+
+    fn help(self) -> Option<String> {
+        Some(match self {
+            Self::WholeFnBody => "consider wrapping only the unsafe operations in `unsafe` blocks, \
+                or making the function `unsafe` if its callers have to uphold safety requirements"
+                .into(),
+            Self::SafeOperands => "consider binding the operands to variables before the `unsafe` block".into(),
+            Self::UnsafeFnBody => {
+                "consider wrapping only the unsafe operations in `unsafe` blocks, and denying `unsafe_op_in_unsafe_fn`"
+                    .into()
+            },
+            Self::TooManyOps { max: 1, .. } => {
+                "consider wrapping each unsafe operation in its own `unsafe` block".into()
+            },
+            Self::TooManyOps { max, .. } => {
+                format!("consider splitting the `unsafe` block into blocks of at most {max} unsafe operations")
+            },
+            Self::TooManyLines { .. } => {
+                "consider moving safe code out of the `unsafe` block, or splitting it into smaller blocks".into()
+            },
+            Self::NestedTooDeeply { .. } => "consider moving the code out of the outer `unsafe` blocks".into(),
+            Self::SafeStmtsBeforeTail
+            | Self::WholeDiscard
+            | Self::CoversStmts { .. }
+            | Self::WrapsUnsafeBlock
+            | Self::SafeTail(_) => return None,
+        })
+    }
+}
+
+/// The kind of a tail expression that covers more than the unsafe operations in it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TailKind {
+    Block,
+    If,
+    Array,
+    Tuple,
+    MethodCall,
+    Call,
+    Other,
+}
+
+impl TailKind {
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Block => "a block",
+            Self::If => "an `if` expression",
+            Self::Array => "an array",
+            Self::Tuple => "a tuple",
+            Self::MethodCall => "a safe method call",
+            Self::Call => "a safe function call",
+            Self::Other => "a safe expression",
+        }
+    }
+}
+
+#[expect(clippy::struct_excessive_bools)] // Bools are configuration options.
+pub struct MinimalUnsafeBlock {
+    accept_commented_blocks: bool,
+    allow_let_wrapping: bool,
+    allow_unsafe_stmts: bool,
+    /// The paths from `minimal-unsafe-block-allowed-callees`, split into their segments.
+    allowed_callees: Vec<Vec<&'static str>>,
+    /// The paths from `allowed-unsafe-block-modules`, split into their segments.
+    allowed_modules: Vec<Vec<&'static str>>,
+    check_generated_code: bool,
+    check_macros: bool,
+    check_tests: bool,
+    check_unsafe_fn_bodies: bool,
+    count_target_feature_calls: bool,
+    counted_ops: &'static [UnsafeOperationKind],
+    follow_unsafe_op_in_unsafe_fn: bool,
+    region_notes: bool,
+    require_single_op: bool,
+    split_method_chains: bool,
+    strict: bool,
+    suggestions: UnsafeBlockSuggestions,
+    max_ops: usize,
+    max_lines: usize,
+    max_nesting: usize,
+    /// The output directory of the build script, files included from it are generated code.
+    out_dir: Option<PathBuf>,
+    /// The linted blocks the pass is currently inside of. Blocks nested in them are subsumed by
+    /// their diagnostic and aren't linted on their own.
+    reported: Vec<HirId>,
+    /// The spans of the blocks linted in the current body. A macro can expand the same block
+    /// several times, which is only linted once.
+    linted_spans: FxHashSet<Span>,
+}
+
+impl MinimalUnsafeBlock {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            accept_commented_blocks: conf.minimal_unsafe_block_accept_commented_blocks,
+            allow_let_wrapping: conf.minimal_unsafe_block_allow_let_wrapping,
+            allow_unsafe_stmts: conf.minimal_unsafe_block_allow_unsafe_statements,
+            allowed_callees: conf
+                .minimal_unsafe_block_allowed_callees
+                .iter()
+                .map(|path| path.split("::").collect())
+                .collect(),
+            allowed_modules: conf
+                .allowed_unsafe_block_modules
+                .iter()
+                .map(|path| path.split("::").collect())
+                .collect(),
+            check_generated_code: conf.minimal_unsafe_block_check_generated_code,
+            check_macros: conf.minimal_unsafe_block_check_macros,
+            check_tests: conf.minimal_unsafe_block_check_tests,
+            check_unsafe_fn_bodies: conf.minimal_unsafe_block_check_unsafe_fn_bodies,
+            count_target_feature_calls: conf.minimal_unsafe_block_count_target_feature_calls,
+            counted_ops: &conf.minimal_unsafe_block_counted_operations,
+            follow_unsafe_op_in_unsafe_fn: conf.minimal_unsafe_block_follow_unsafe_op_in_unsafe_fn,
+            region_notes: conf.minimal_unsafe_block_region_notes,
+            require_single_op: conf.minimal_unsafe_block_require_single_op,
+            split_method_chains: conf.minimal_unsafe_block_split_method_chains,
+            strict: conf.minimal_unsafe_block_strict,
+            suggestions: conf.minimal_unsafe_block_suggestions,
+            max_ops: usize::try_from(conf.max_unsafe_operations_per_block).unwrap_or(usize::MAX),
+            max_lines: usize::try_from(conf.unsafe_block_max_lines).unwrap_or(usize::MAX),
+            max_nesting: usize::try_from(conf.max_unsafe_block_nesting).unwrap_or(usize::MAX),
+            out_dir: if conf.minimal_unsafe_block_check_generated_code {
+                None
+            } else {
+                env::var_os("OUT_DIR").map(|dir| fs::canonicalize(&dir).unwrap_or_else(|_| dir.into()))
+            },
+            reported: Vec::new(),
+            linted_spans: FxHashSet::default(),
+        }
+    }
+
+    fn is_inside_reported_block(&self, cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+        !self.reported.is_empty()
+            && cx
+                .tcx
+                .hir()
+                .parent_iter(block.hir_id)
+                .take_while(|(_, node)| !matches!(node, Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_)))
+                .any(|(id, _)| self.reported.contains(&id))
+    }
+
+    /// Checks whether the code is exempt from the lint by its location, according to the
+    /// configuration.
+    fn is_exempt(&self, cx: &LateContext<'_>, span: Span, hir_id: HirId) -> bool {
+        in_external_macro(cx.tcx.sess, span)
+            || (!self.check_macros && span.from_expansion())
+            || (!self.check_tests && is_in_test(cx.tcx, hir_id))
+            || (!self.check_generated_code && self.is_generated_code(cx, span, hir_id))
+            || matches_any_path(cx, cx.tcx.parent_module(hir_id).to_def_id(), &self.allowed_modules)
+    }
+
+    /// Checks whether the code is part of an `#[automatically_derived]` item or of a file included
+    /// from the output directory of the build script.
+    fn is_generated_code(&self, cx: &LateContext<'_>, span: Span, hir_id: HirId) -> bool {
+        in_automatically_derived(cx.tcx, hir_id)
+            || self.out_dir.as_deref().is_some_and(|out_dir| {
+                let file = cx.sess().source_map().lookup_source_file(span.source_callsite().lo());
+                if let FileName::Real(name) = &file.name
+                    && let Some(path) = name.local_path()
+                {
+                    fs::canonicalize(path).is_ok_and(|path| path.starts_with(out_dir))
+                } else {
+                    false
+                }
+            })
+    }
+
+    /// Checks whether every statement of the block performs an unsafe operation, and all of them
+    /// are calls to allowed callees or of kinds that don't count.
+    fn only_exempt_ops<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        block: &'tcx Block<'tcx>,
+        stmts: &[&'tcx Stmt<'tcx>],
+    ) -> bool {
+        let ops = unsafe_ops(cx, block);
+        !ops.is_empty()
+            && ops.iter().all(|op| {
+                unsafe_op_kind(cx, op).is_some_and(|kind| !self.counted_ops.contains(&kind))
+                    || called_fn(cx, op).is_some_and(|(id, _)| matches_any_path(cx, id, &self.allowed_callees))
+            })
+            && all_stmts_unsafe(cx, block, stmts)
+    }
+
+    /// Checks whether the unsafe operation counts towards the limit of operations per block.
+    fn counts_op<'tcx>(&self, cx: &LateContext<'tcx>, op: &'tcx Expr<'tcx>) -> bool {
+        unsafe_op_kind(cx, op).is_some_and(|kind| self.counted_ops.contains(&kind))
+            && (self.count_target_feature_calls
+                || !called_fn(cx, op).is_some_and(|(id, _)| is_safe_target_feature_call(cx, id, op)))
+    }
+}
+
+impl_lint_pass!(MinimalUnsafeBlock => [MINIMAL_UNSAFE_BLOCK, MOSTLY_SAFE_UNSAFE_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for MinimalUnsafeBlock {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.rules != BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            || block.span.is_desugaring(DesugaringKind::Await)
+            || self.is_exempt(cx, block.span, block.hir_id)
+            || self.is_inside_reported_block(cx, block)
+            || self.linted_spans.contains(&block.span)
+        {
+            return;
+        }
+
+        // Where `unsafe_op_in_unsafe_fn` is allowed, the blocks are redundant anyway, and where it
+        // isn't, each unsafe operation is expected to get a block of its own.
+        let in_unsafe_fn = self.follow_unsafe_op_in_unsafe_fn && is_in_unsafe_fn(cx, block.hir_id);
+        if in_unsafe_fn && is_lint_allowed(cx, UNSAFE_OP_IN_UNSAFE_FN, block.hir_id) {
+            return;
+        }
+
+        // Nested items are inert, only the executable statements matter.
+        let stmts: Vec<_> = block
+            .stmts
+            .iter()
+            .filter(|stmt| !matches!(stmt.kind, StmtKind::Item(_)))
+            .collect();
+        if self.only_exempt_ops(cx, block, &stmts) {
+            return;
+        }
+        // A block without any unsafe operation is the business of `unused_unsafe`.
+        if !contains_unsafe_op(cx, block) && !is_lint_allowed(cx, UNUSED_UNSAFE, block.hir_id) {
+            return;
+        }
+        let max_ops = if self.require_single_op || in_unsafe_fn {
+            1
+        } else {
+            self.max_ops
+        };
+        let linted = if stmts.is_empty() {
+            block.expr.is_some_and(|tail| {
+                (self.split_method_chains || !is_unsafe_method_chain(cx, tail))
+                    && check_tail(cx, block, tail, self.suggestions, self.region_notes)
+            })
+        } else {
+            let accepted = (self.accept_commented_blocks && block_has_safety_comment(cx, block.span))
+                || (self.allow_let_wrapping && is_wrapped_let(cx, block, &stmts))
+                || (!self.split_method_chains
+                    && block.expr.is_none()
+                    && matches!(
+                        stmts[..],
+                        [Stmt { kind: StmtKind::Expr(expr) | StmtKind::Semi(expr), .. }]
+                            if is_unsafe_method_chain(cx, expr)
+                    ))
+                || ((self.allow_unsafe_stmts || max_ops > 0) && all_stmts_unsafe(cx, block, &stmts));
+            !accepted && check_stmts(cx, block, &stmts, self.suggestions, self.region_notes)
+        };
+        if linted
+            || (self.strict && check_safe_operands(cx, block, &stmts))
+            || (max_ops > 0 && check_op_count(cx, block, max_ops, |op| self.counts_op(cx, op)))
+            || (self.max_lines > 0 && check_line_count(cx, block, self.max_lines))
+            || (self.max_nesting > 0 && check_nesting(cx, block, self.max_nesting))
+        {
+            self.reported.push(block.hir_id);
+            self.linted_spans.insert(block.span);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        _: Span,
+        def_id: LocalDefId,
+    ) {
+        let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+        if kind
+            .header()
+            .is_some_and(|header| header.is_unsafe() && !header.is_async())
+            && let ExprKind::Block(block, _) = body.value.kind
+            && !is_lint_allowed(cx, MOSTLY_SAFE_UNSAFE_FN, hir_id)
+            && !is_trait_impl_item(cx, hir_id)
+            && !self.is_exempt(cx, block.span, block.hir_id)
+            && !has_safety_section(cx, hir_id)
+        {
+            check_mostly_safe_fn(cx, def_id, block);
+        }
+
+        if self.check_unsafe_fn_bodies
+            && kind
+                .header()
+                .is_some_and(|header| header.is_unsafe() && !header.is_async())
+            && let ExprKind::Block(block, _) = body.value.kind
+            && is_lint_allowed(cx, UNSAFE_OP_IN_UNSAFE_FN, body.value.hir_id)
+            && !self.is_exempt(cx, block.span, block.hir_id)
+        {
+            check_unsafe_fn_body(cx, block);
+        }
+    }
+
+    fn check_block_post(&mut self, _: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if self.reported.last() == Some(&block.hir_id) {
+            self.reported.pop();
+        }
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &Body<'tcx>) {
+        // Closures and inline consts are checked as part of their parent body.
+        if !cx
+            .tcx
+            .is_typeck_child(cx.tcx.hir().body_owner_def_id(body.id()).to_def_id())
+        {
+            self.reported.clear();
+            self.linted_spans.clear();
+        }
+    }
+}
+
+fn check_stmts<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    suggestions: UnsafeBlockSuggestions,
+    region_notes: bool,
+) -> bool {
+    let (stmt_spans, unsafe_stmts) = stmt_units(cx, block.span.ctxt(), stmts);
+    let unsafe_tail = block.expr.is_some_and(|tail| contains_unsafe_op(cx, tail));
+
+    // A block without any unsafe operation is the business of `unused_unsafe`.
+    if !unsafe_tail && !unsafe_stmts.contains(&true) {
+        return false;
+    }
+
+    // A single unsafe operation without a value, as in `unsafe { ptr.write(1); }`, is as narrow
+    // as a block with a tail expression, and the form `clippy::semicolon_if_nothing_returned`
+    // asks for when it spans several lines.
+    if let ([stmt], None) = (stmts, block.expr)
+        && let StmtKind::Semi(expr) = stmt.kind
+        && is_minimal_expr(cx, expr)
+        && cx.typeck_results().expr_ty(expr).is_unit()
+    {
+        return false;
+    }
+
+    // The statements of a single macro call, as in `unsafe { write_reg!(ptr, 1); }`, can't be
+    // narrowed down any further.
+    if block.expr.is_none()
+        && !stmts[0].span.eq_ctxt(block.span)
+        && stmt_spans.iter().all(|span| *span == stmt_spans[0])
+    {
+        return false;
+    }
+
+    let (leading, trailing) = safe_edges(&unsafe_stmts, unsafe_tail);
+    let (safe_prefix, rest) = stmts.split_at(leading);
+    let safe_suffix = &rest[rest.len() - trailing..];
+
+    // Moving bindings or the tail expression to another scope may change when values are dropped.
+    if safe_prefix
+        .iter()
+        .chain(safe_suffix)
+        .any(|stmt| binds_ordered_drop_value(cx, stmt))
+        || block
+            .expr
+            .is_some_and(|tail| any_temporaries_need_ordered_drop(cx, tail))
+    {
+        return false;
+    }
+
+    let only_tail_unsafe = unsafe_tail && !unsafe_stmts.contains(&true);
+    let (stmt_count, ops) = covered_ops(cx, block, stmts, &stmt_spans);
+    let reason = if is_whole_fn_body(cx, block) {
+        Reason::WholeFnBody
+    } else if only_tail_unsafe {
+        Reason::SafeStmtsBeforeTail
+    } else if let ([stmt], None) = (stmts, block.expr)
+        && is_discard(stmt)
+    {
+        Reason::WholeDiscard
+    } else {
+        Reason::CoversStmts {
+            stmts: stmt_count,
+            ops: ops.len(),
+        }
+    };
+    let whole_fn_body = reason == Reason::WholeFnBody;
+    let ffi = is_ffi_block(cx, block);
+
+    let prefix_spans = &stmt_spans[..leading];
+    let suffix_spans = &stmt_spans[stmts.len() - trailing..];
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        excess_span(block, prefix_spans, suffix_spans),
+        reason.msg(ffi),
+        |diag| {
+            if leading + trailing > 0 {
+                diag.span_label(unsafe_keyword_span(cx, block), "in this `unsafe` block");
+            }
+            reason.add_help(diag);
+            label_safe_stmts(
+                diag,
+                prefix_spans,
+                "this statement can be moved before the `unsafe` block",
+            );
+            label_safe_stmts(
+                diag,
+                suffix_spans,
+                "this statement can be moved after the `unsafe` block",
+            );
+            label_nested_safe_stmts(cx, diag, stmts, &unsafe_stmts, block.expr.filter(|_| unsafe_tail));
+            label_unsafe_ops(cx, diag, block);
+            if let Some(summary) = ops_summary(reason, stmt_count, ops.len()) {
+                diag.note(summary);
+            }
+            note_unsafe_context(cx, diag, block, &ops);
+            if !(whole_fn_body && suggest_whole_body_rewrite(cx, diag, block)) {
+                suggest_narrowing(cx, diag, block, stmts, &unsafe_stmts, unsafe_tail, whole_fn_body);
+            }
+            note_narrowable_tail(cx, diag, block);
+            help_safe_wrapper(cx, diag, block);
+            if ffi {
+                help_ffi_config(diag);
+            }
+            note_safety_comments(cx, diag, block);
+            if region_notes {
+                note_regions(cx, diag, block);
+            }
+            restrict_suggestions(diag, suggestions);
+        },
+    );
+    true
+}
+
+/// Points out a tail expression of a block covering statements that can be narrowed as well.
+fn note_narrowable_tail<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) {
+    if let Some(tail) = block.expr
+        && let Some(kind) = non_minimal_tail(cx, tail)
+    {
+        diag.span_note(
+            tail.span,
+            format!(
+                "the tail expression is {} that can be narrowed as well",
+                kind.describe()
+            ),
+        );
+    }
+}
+
+/// Returns the span to point at for a block covering statements: the statements that can be moved
+/// out of it if there are any, or else the whole block.
+fn excess_span(block: &Block<'_>, prefix_spans: &[Span], suffix_spans: &[Span]) -> MultiSpan {
+    let mut excess: Vec<Span> = prefix_spans.iter().chain(suffix_spans).copied().collect();
+    excess.dedup();
+    if excess.is_empty() {
+        MultiSpan::from_span(block.span)
+    } else {
+        MultiSpan::from_spans(excess)
+    }
+}
+
+/// Checks whether the block only consists of a `let` statement initialized by an unsafe operation
+/// and possibly the bound variable, as in `unsafe { let x = ptr.read(); x }`, for use with
+/// `minimal-unsafe-block-allow-let-wrapping`.
+fn is_wrapped_let<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, stmts: &[&'tcx Stmt<'tcx>]) -> bool {
+    if let [stmt] = stmts
+        && let StmtKind::Let(LetStmt {
+            pat,
+            init: Some(init),
+            els: None,
+            ..
+        }) = stmt.kind
+        && is_minimal_expr(cx, init)
+    {
+        block.expr.is_none_or(|tail| {
+            path_to_local(tail).is_some_and(|id| {
+                let mut is_bound = false;
+                pat.each_binding_or_first(&mut |_, binding, _, _| is_bound |= binding == id);
+                is_bound
+            })
+        })
+    } else {
+        false
+    }
+}
+
+/// Checks whether every statement of the block, and its tail expression if there is one, performs
+/// an unsafe operation, for use with `minimal-unsafe-block-allow-unsafe-statements`.
+fn all_stmts_unsafe<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, stmts: &[&'tcx Stmt<'tcx>]) -> bool {
+    let (_, unsafe_stmts) = stmt_units(cx, block.span.ctxt(), stmts);
+    !unsafe_stmts.contains(&false) && block.expr.is_none_or(|tail| contains_unsafe_op(cx, tail))
+}
+
+/// Returns the spans of the statements in the given context and whether they contain unsafe
+/// operations. Statements expanded from the same macro call, as in `write_reg!(ptr, 1);`, can only
+/// be moved together, so they share the span of the call and are unsafe if any of them is.
+fn stmt_units<'tcx>(cx: &LateContext<'tcx>, ctxt: SyntaxContext, stmts: &[&'tcx Stmt<'tcx>]) -> (Vec<Span>, Vec<bool>) {
+    let spans: Vec<Span> = stmts
+        .iter()
+        .map(|stmt| walk_span_to_context(stmt.span, ctxt).unwrap_or(stmt.span))
+        .collect();
+    let has_unsafe_op: Vec<bool> = stmts.iter().map(|stmt| contains_unsafe_op(cx, *stmt)).collect();
+    let unsafe_stmts = spans
+        .iter()
+        .map(|span| {
+            spans
+                .iter()
+                .zip(&has_unsafe_op)
+                .any(|(other, is_unsafe)| *is_unsafe && other == span)
+        })
+        .collect();
+    (spans, unsafe_stmts)
+}
+
+/// Labels the given statement spans, skipping repeated spans of statements from the same macro
+/// call.
+fn label_safe_stmts(diag: &mut Diag<'_, ()>, spans: &[Span], label: &'static str) {
+    let mut spans = spans.to_vec();
+    spans.dedup();
+    for span in spans {
+        diag.span_label(span, label);
+    }
+}
+
+/// Labels the unsafe operations of the block, which have to stay inside of an `unsafe` block.
+/// Operations expanded from the same macro call share the label of the call.
+fn label_unsafe_ops<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) {
+    let mut labeled = Vec::new();
+    for op in unsafe_ops(cx, block) {
+        let span = walk_span_to_context(op.span, block.span.ctxt()).unwrap_or(op.span);
+        let from_macro = span != op.span;
+        // Only point at the method itself, as its receiver may be a chain of other calls.
+        let span = match op.kind {
+            ExprKind::MethodCall(segment, ..) if !from_macro && segment.ident.span.eq_ctxt(span) => {
+                segment.ident.span.with_hi(span.hi())
+            },
+            _ => span,
+        };
+        if labeled.contains(&span) {
+            continue;
+        }
+        labeled.push(span);
+        let label = match unsafe_op_kind(cx, op) {
+            _ if from_macro => "this macro call requires `unsafe`",
+            Some(UnsafeOperationKind::RawPointerDeref) => "this dereference requires `unsafe`",
+            Some(UnsafeOperationKind::StaticAccess) => "this access to a static requires `unsafe`",
+            Some(UnsafeOperationKind::UnionFieldAccess) => "this union field access requires `unsafe`",
+            Some(UnsafeOperationKind::InlineAsm) => "this inline assembly requires `unsafe`",
+            Some(UnsafeOperationKind::UnsafeCall) | None => "this call requires `unsafe`",
+        };
+        diag.span_label(span, label);
+    }
+}
+
+/// Adds notes pointing at the unsafe operations in the block and at the narrowest region covering
+/// all of them, for tools that build their own code actions from the JSON diagnostics.
+fn note_regions<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) {
+    let mut spans: Vec<Span> = unsafe_ops(cx, block)
+        .iter()
+        .map(|op| walk_span_to_context(op.span, block.span.ctxt()).unwrap_or(op.span))
+        .collect();
+    spans.sort_by_key(|span| (span.lo(), span.hi()));
+    spans.dedup();
+    let (Some(&first), Some(&last)) = (spans.first(), spans.last()) else {
+        return;
+    };
+    let region = first.to(last);
+    diag.span_note(spans, "the unsafe operations of the block are here");
+    diag.span_note(region, "the narrowest `unsafe` block would cover this region");
+}
+
+/// Returns the number of safe statements at the start and at the end of a block, which can be
+/// moved out of it without splitting it up.
+fn safe_edges(unsafe_stmts: &[bool], unsafe_tail: bool) -> (usize, usize) {
+    let leading = unsafe_stmts.iter().take_while(|is_unsafe| !**is_unsafe).count();
+    let trailing = if unsafe_tail {
+        0
+    } else {
+        unsafe_stmts.iter().rev().take_while(|is_unsafe| !**is_unsafe).count()
+    };
+    (leading, trailing)
+}
+
+/// Recurses into the plain blocks among the given statements and tail expression that contain
+/// unsafe operations, as in `unsafe { { let x = 1; unsafe_fn(x); } }`, and labels the safe
+/// statements at their edges.
+fn label_nested_safe_stmts<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    unsafe_stmts: &[bool],
+    tail: Option<&'tcx Expr<'tcx>>,
+) {
+    let unsafe_exprs = stmts
+        .iter()
+        .zip(unsafe_stmts)
+        .filter(|&(_, is_unsafe)| *is_unsafe)
+        .filter_map(|(stmt, _)| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Let(_) | StmtKind::Item(_) => None,
+        })
+        .chain(tail);
+    for expr in unsafe_exprs {
+        if let ExprKind::Block(block, None) = expr.kind
+            && block.rules == BlockCheckMode::DefaultBlock
+            && block.span.eq_ctxt(expr.span)
+        {
+            let stmts: Vec<_> = block
+                .stmts
+                .iter()
+                .filter(|stmt| !matches!(stmt.kind, StmtKind::Item(_)))
+                .collect();
+            let (stmt_spans, unsafe_stmts) = stmt_units(cx, block.span.ctxt(), &stmts);
+            let unsafe_tail = block.expr.is_some_and(|tail| contains_unsafe_op(cx, tail));
+            let (leading, trailing) = safe_edges(&unsafe_stmts, unsafe_tail);
+            let movable: Vec<_> = stmts
+                .iter()
+                .zip(stmt_spans)
+                .enumerate()
+                .filter(|&(i, (stmt, _))| {
+                    (i < leading || i >= stmts.len() - trailing) && !binds_ordered_drop_value(cx, stmt)
+                })
+                .map(|(_, (_, span))| span)
+                .collect();
+            label_safe_stmts(
+                diag,
+                &movable,
+                "this statement does not need to be inside the `unsafe` block",
+            );
+            label_nested_safe_stmts(cx, diag, &stmts, &unsafe_stmts, block.expr.filter(|_| unsafe_tail));
+        }
+    }
+}
+
+/// Adds the most specific suggestion for narrowing the `unsafe` block to `diag`.
+fn suggest_narrowing<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    unsafe_stmts: &[bool],
+    unsafe_tail: bool,
+    whole_fn_body: bool,
+) {
+    if let Some(flow) = escaping_control_flow(cx, block, stmts) {
+        if suggest_hoisting(cx, diag, block, stmts) {
+            diag.span_note(
+                flow.span,
+                "this expression affects control flow, so only whole statements are moved out of the `unsafe` block",
+            );
+        } else {
+            diag.span_note(
+                flow.span,
+                "this expression affects control flow, so the `unsafe` block has to be narrowed by hand",
+            );
+            if !whole_fn_body {
+                diag.help("consider narrowing the `unsafe` block to the unsafe operations");
+            }
+        }
+    } else if let Some(parts) = unsafe_parts(cx, block, stmts, unsafe_stmts, unsafe_tail) {
+        let mut sugg = unwrap_block_parts(cx, block);
+        // Without its braces, the contents of the block end up one level of nesting further out.
+        let unwrapped = match sugg[..] {
+            [(open, _), (close, _)] => Some(open.to(close)),
+            _ => None,
+        };
+        let mut app = if let [part] = &parts[..]
+            && (part.operand.hir_id == part.expr.hir_id
+                || matches!(part.operand.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)))
+            && !removes_comments(cx, &sugg)
+        {
+            Applicability::MachineApplicable
+        } else {
+            // Moving a place expression into a block turns it into a value.
+            Applicability::MaybeIncorrect
+        };
+        if parts.iter().any(|part| in_macro_context(cx, block, part.operand)) {
+            app = Applicability::MaybeIncorrect;
+        }
+        for part in &parts {
+            sugg.push(wrap_in_unsafe(cx, part.operand, block.span.ctxt(), None, &mut app));
+        }
+        relocate_safety_comment(cx, block, parts.iter().map(|part| part.operand.span), &mut sugg);
+        if let Some(unwrapped) = unwrapped {
+            reindent_sugg(cx, unwrapped, &mut sugg, &mut app);
+        }
+        diag.multipart_suggestion(narrowing_msg(cx, block, &parts), sugg, app);
+        for els in parts.iter().filter_map(|part| part.els) {
+            diag.span_note(
+                els.span,
+                "the `else` branch does not need to be inside the `unsafe` block",
+            );
+        }
+    } else if !suggest_hoisting(cx, diag, block, stmts) && !whole_fn_body {
+        diag.help("consider narrowing the `unsafe` block to the unsafe operations");
+    }
+}
+
+/// Suggests moving the safe statements at the edges of the block out of it, for blocks whose
+/// statements with unsafe operations can't be narrowed one by one. Returns whether a suggestion
+/// was added.
+fn suggest_hoisting<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+) -> bool {
+    // Nested items would have to be moved along with the statements around them.
+    if stmts.len() != block.stmts.len() {
+        return false;
+    }
+    let Some(site) = hoisting_site(cx, block) else {
+        return false;
+    };
+    let (leading, trailing) = hoistable_edges(cx, block, stmts, &site);
+    if leading == 0 && trailing == 0 {
+        return false;
+    }
+
+    let ctxt = block.span.ctxt();
+    let (mut stmt_spans, _) = stmt_units(cx, ctxt, stmts);
+    // The span of a macro call used as a statement doesn't include its semicolon.
+    for span in &mut stmt_spans {
+        let next = span.shrink_to_hi().with_hi(span.hi() + BytePos(1));
+        if snippet_opt(cx, next).as_deref() == Some(";") {
+            *span = span.with_hi(next.hi());
+        }
+    }
+    let tail_span = block.expr.and_then(|tail| walk_span_to_context(tail.span, ctxt));
+    let middle_lo = stmt_spans
+        .get(leading)
+        .map(|span| span.lo())
+        .or(tail_span.map(Span::lo));
+    let middle_hi = if trailing > 0 {
+        Some(stmt_spans[stmts.len() - trailing - 1].hi())
+    } else {
+        tail_span.or(stmt_spans.last().copied()).map(Span::hi)
+    };
+    let (Some(middle_lo), Some(middle_hi)) = (middle_lo, middle_hi) else {
+        return false;
+    };
+    let middle = block.span.with_lo(middle_lo).with_hi(middle_hi);
+    let before = (leading > 0).then(|| stmt_spans[0].to(stmt_spans[leading - 1]));
+    let after = (trailing > 0).then(|| stmt_spans[stmts.len() - trailing].to(stmt_spans[stmts.len() - 1]));
+    if snippet_opt(cx, block.span).is_none_or(|src| has_multiline_literal(&src)) {
+        return false;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let indent = snippet_indent(cx, site.span).unwrap_or_default();
+    let middle_stmts = &stmts[leading..stmts.len() - trailing];
+    let unsafe_block = hoisted_unsafe_block(cx, block, middle_stmts, middle, &site, &indent, &mut app);
+
+    // The `// SAFETY:` comment of the block has to stay right above it.
+    let block_line = first_line_of_span(cx, block.span).shrink_to_lo();
+    let (start, comment) = match block_safety_comment_start(cx, block.span) {
+        Some(start) if before.is_some() && block_line.lo() == site.span.lo() => (
+            start,
+            snippet_opt(cx, block_line.with_lo(start)).map(|comment| {
+                let lines: Vec<_> = comment.trim_end().lines().map(str::trim_start).collect();
+                format!("{}\n{indent}", lines.join(&format!("\n{indent}")))
+            }),
+        ),
+        _ => (site.span.lo(), None),
+    };
+    let mut prefix = String::new();
+    if let Some(before) = before {
+        prefix = format!("{}\n{indent}", reindented(cx, before, indent.len(), &mut app));
+    }
+    prefix.push_str(comment.as_deref().unwrap_or_default());
+
+    let sugg = if site.in_let {
+        vec![
+            (site.span.with_lo(start).with_hi(site.span.lo()), prefix),
+            (block.span, unsafe_block),
+        ]
+    } else {
+        let mut replacement = prefix + &unsafe_block;
+        if let Some(after) = after {
+            replacement.push_str(&format!("\n{indent}{}", reindented(cx, after, indent.len(), &mut app)));
+        }
+        vec![(site.span.with_lo(start), replacement)]
+    };
+
+    // Comments between the moved statements would be lost, and moved bindings may shadow names
+    // used after the block.
+    let gaps = [
+        Some(block.span.with_hi(before.unwrap_or(middle).lo())),
+        before.map(|before| middle.with_lo(before.hi()).with_hi(middle.lo())),
+        after.map(|after| middle.with_lo(middle.hi()).with_hi(after.lo())),
+        Some(block.span.with_lo(after.unwrap_or(middle).hi())),
+    ];
+    let moves_bindings = stmts[..leading]
+        .iter()
+        .chain(&stmts[stmts.len() - trailing..])
+        .any(|stmt| matches!(stmt.kind, StmtKind::Let(local) if !matches!(local.pat.kind, PatKind::Wild)));
+    if gaps
+        .into_iter()
+        .flatten()
+        .any(|gap| span_contains_comment(cx.sess().source_map(), gap))
+        || (moves_bindings && !site.is_tail && !is_last_stmt(cx, site.id))
+    {
+        app = Applicability::MaybeIncorrect;
+    }
+    diag.multipart_suggestion(
+        "consider moving the safe statements out of the `unsafe` block",
+        sugg,
+        app,
+    );
+    true
+}
+
+/// Where statements moved out of an `unsafe` block end up.
+struct HoistingSite {
+    /// The statement the block is part of, or the block itself if it's the tail expression of
+    /// another block. Statements can only be moved before the tail expression or a `let`
+    /// initializer.
+    span: Span,
+    id: HirId,
+    in_let: bool,
+    is_tail: bool,
+}
+
+fn hoisting_site(cx: &LateContext<'_>, block: &Block<'_>) -> Option<HoistingSite> {
+    let Node::Expr(block_expr) = cx.tcx.parent_hir_node(block.hir_id) else {
+        return None;
+    };
+    let (span, id, in_let, is_tail) = match cx.tcx.parent_hir_node(block_expr.hir_id) {
+        Node::Stmt(stmt) => (stmt.span, stmt.hir_id, false, false),
+        Node::LetStmt(local) if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(local.hir_id) => {
+            (stmt.span, stmt.hir_id, true, false)
+        },
+        Node::Block(outer) if outer.expr.is_some_and(|tail| tail.hir_id == block_expr.hir_id) => {
+            (block_expr.span, block_expr.hir_id, false, true)
+        },
+        _ => return None,
+    };
+    span.eq_ctxt(block.span).then_some(HoistingSite {
+        span,
+        id,
+        in_let,
+        is_tail,
+    })
+}
+
+/// Returns the number of safe statements at the start and at the end of the block that can be
+/// moved out of it. Trailing statements can only be moved after a block used as a statement, and
+/// can't use the bindings of the statements staying in the block.
+fn hoistable_edges<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    site: &HoistingSite,
+) -> (usize, usize) {
+    let (_, unsafe_stmts) = stmt_units(cx, block.span.ctxt(), stmts);
+    let unsafe_tail = block.expr.is_some_and(|tail| contains_unsafe_op(cx, tail));
+    let (leading, trailing) = safe_edges(&unsafe_stmts, unsafe_tail);
+    let (rest, trailing_stmts) = stmts.split_at(stmts.len() - trailing);
+    let binds_used_later = |stmt: &Stmt<'tcx>| {
+        let mut used = false;
+        if let StmtKind::Let(local) = stmt.kind {
+            local
+                .pat
+                .each_binding(|_, id, _, _| used |= trailing_stmts.iter().any(|later| is_local_used(cx, *later, id)));
+        }
+        used
+    };
+    if site.in_let || site.is_tail || block.expr.is_some() || rest[leading..].iter().any(|stmt| binds_used_later(stmt))
+    {
+        (leading, 0)
+    } else {
+        (leading, trailing)
+    }
+}
+
+/// Renders the `unsafe` block left behind when hoisting statements out of `block`. A single
+/// statement is narrowed to its expression right away, and a nested `unsafe` block doesn't need
+/// another one around it.
+fn hoisted_unsafe_block<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    middle_stmts: &[&'tcx Stmt<'tcx>],
+    middle: Span,
+    site: &HoistingSite,
+    indent: &str,
+    app: &mut Applicability,
+) -> String {
+    // The statement's semicolon has to be kept if the value of the block is used. A macro call
+    // expanding to a statement may not expand to an expression, unlike one in the tail position.
+    let single = match (middle_stmts, block.expr) {
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Semi(expr) if !site.in_let => Some((expr, expr.span, site.is_tail)),
+            StmtKind::Expr(expr) => Some((expr, expr.span, false)),
+            _ => None,
+        }
+        .filter(|(expr, ..)| expr.span.eq_ctxt(block.span)),
+        ([], Some(tail)) => walk_span_to_context(tail.span, block.span.ctxt()).map(|span| (tail, span, false)),
+        _ => None,
+    };
+    let mut unsafe_block = if let Some((expr, span, _)) = single
+        && span == expr.span
+        && is_unsafe_block(expr)
+    {
+        reindented(cx, span, indent.len(), app)
+    } else if let Some((_, span, _)) = single
+        && let snip = reindented(cx, span, indent.len(), app)
+        && !snip.contains('\n')
+    {
+        format!("unsafe {{ {snip} }}")
+    } else {
+        format!(
+            "unsafe {{\n{indent}    {}\n{indent}}}",
+            reindented(cx, middle, indent.len() + 4, app)
+        )
+    };
+    if (!site.in_let && !site.is_tail) || single.is_some_and(|(.., keep_semi)| keep_semi) {
+        unsafe_block.push(';');
+    }
+    unsafe_block
+}
+
+/// Returns the snippet of `span` reindented to `indent`.
+fn reindented(cx: &LateContext<'_>, span: Span, indent: usize, app: &mut Applicability) -> String {
+    let snip = snippet_with_applicability(cx, span, "..", app);
+    reindent_multiline(snip, true, Some(indent)).into_owned()
+}
+
+/// Suggests removing an `unsafe` block making up the whole function body and wrapping each of its
+/// unsafe operations in an `unsafe` block of its own instead. Returns whether a suggestion was
+/// added.
+fn suggest_whole_body_rewrite<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) -> bool {
+    let Node::Expr(block_expr) = cx.tcx.parent_hir_node(block.hir_id) else {
+        return false;
+    };
+    // Breaking out of a labeled block needs the block, and the bodies of nested items may get
+    // suggestions of their own, which can't overlap with this one.
+    if matches!(block_expr.kind, ExprKind::Block(_, Some(_)))
+        || block.stmts.iter().any(|stmt| matches!(stmt.kind, StmtKind::Item(_)))
+        || contains_unsafe_block(cx, block)
+    {
+        return false;
+    }
+
+    let ctxt = block.span.ctxt();
+    let all_operands: Vec<_> = unsafe_ops(cx, block)
+        .into_iter()
+        .map(|op| place_operand(cx, op, block_expr.hir_id))
+        .collect();
+    // Operations nested in others, as in `unsafe_fn(unsafe_fn(0))`, are covered by the outer one.
+    let operands: Vec<_> = all_operands
+        .iter()
+        .copied()
+        .filter(|operand| {
+            !all_operands
+                .iter()
+                .any(|other| other.hir_id != operand.hir_id && other.span.contains(operand.span))
+        })
+        .collect();
+    if operands.is_empty() || operands.iter().any(|operand| operand.span.ctxt() != ctxt) {
+        return false;
+    }
+
+    // The contents of the block take the place of its braces. The semicolon of a block used as a
+    // statement goes as well, unless it follows a tail expression that would otherwise become the
+    // value of the function.
+    let content = block
+        .stmts
+        .iter()
+        .map(|stmt| stmt.span)
+        .chain(block.expr.map(|tail| tail.span))
+        .map(|span| walk_span_to_context(span, ctxt).unwrap_or(span));
+    let (Some(first), Some(mut last)) = (content.clone().next(), content.last()) else {
+        return false;
+    };
+    let next = last.shrink_to_hi().with_hi(last.hi() + BytePos(1));
+    if block.expr.is_none() && snippet_opt(cx, next).as_deref() == Some(";") {
+        last = last.with_hi(next.hi());
+    }
+    let end = match cx.tcx.parent_hir_node(block_expr.hir_id) {
+        Node::Stmt(stmt) if block.expr.is_none() && stmt.span.eq_ctxt(block.span) => stmt.span.hi(),
+        _ => block.span.hi(),
+    };
+    let mut sugg = vec![
+        (block.span.with_hi(first.lo()), String::new()),
+        (block.span.with_lo(last.hi()).with_hi(end), String::new()),
+    ];
+
+    let mut app =
+        if removes_comments(cx, &sugg) || block.stmts.len() + usize::from(block.expr.is_some()) > LARGE_BODY_STMTS {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+    for operand in &operands {
+        // Moving a place expression into a block turns it into a value.
+        if !matches!(
+            operand.kind,
+            ExprKind::Call(..) | ExprKind::MethodCall(..) | ExprKind::Assign(..) | ExprKind::AssignOp(..)
+        ) && !matches!(cx.tcx.parent_hir_node(operand.hir_id), Node::Stmt(_) | Node::LetStmt(_))
+            || in_macro_context(cx, block, operand)
+        {
+            app = Applicability::MaybeIncorrect;
+        }
+        sugg.push(wrap_in_unsafe(cx, operand, ctxt, None, &mut app));
+    }
+    relocate_safety_comment(cx, block, operands.iter().map(|operand| operand.span), &mut sugg);
+    reindent_sugg(cx, block.span.with_hi(end), &mut sugg, &mut app);
+    let msg = if let [_] = &operands[..] {
+        "consider removing the `unsafe` block around the function body and wrapping only the unsafe operation"
+    } else {
+        "consider removing the `unsafe` block around the function body and wrapping each unsafe operation individually"
+    };
+    diag.multipart_suggestion(msg, sugg, app);
+    true
+}
+
+/// Checks whether the statement is the last one of its block, with no tail expression following.
+fn is_last_stmt(cx: &LateContext<'_>, stmt: HirId) -> bool {
+    matches!(cx.tcx.parent_hir_node(stmt), Node::Block(block)
+        if block.expr.is_none() && block.stmts.last().is_some_and(|last| last.hir_id == stmt))
+}
+
+/// Returns the first `return`, `break`, `continue` or `?` in the block that leaves the block.
+/// Moving such an expression around or rewrapping it may change where control flow continues,
+/// e.g. when it breaks out of a labeled `unsafe` block. The `else` branch of a `let`-`else` is
+/// left in place by every suggestion and thus not considered.
+fn escaping_control_flow<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+) -> Option<&'tcx Expr<'tcx>> {
+    let find = |node: &'tcx Expr<'tcx>| {
+        for_each_expr_without_closures(node, |e| {
+            let escapes = match e.kind {
+                ExprKind::Ret(_) | ExprKind::Match(_, _, MatchSource::TryDesugar(_)) => true,
+                ExprKind::Break(dest, _) | ExprKind::Continue(dest) => dest
+                    .target_id
+                    .is_ok_and(|target| !cx.tcx.hir().parent_id_iter(target).any(|id| id == block.hir_id)),
+                _ => false,
+            };
+            if escapes {
+                ControlFlow::Break(e)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+    };
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Let(local) => local.init,
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Item(_) => None,
+        })
+        .chain(block.expr)
+        .find_map(find)
+}
+
+/// A statement's expression, `let` initializer or the tail expression with unsafe operations,
+/// which gets wrapped in an `unsafe` block of its own when narrowing the block.
+struct UnsafePart<'tcx> {
+    expr: &'tcx Expr<'tcx>,
+    /// The part of `expr` to wrap, which is only its unsafe operation if it has a single one.
+    operand: &'tcx Expr<'tcx>,
+    /// The `else` branch of a `let`-`else`, which is moved out of the `unsafe` block.
+    els: Option<&'tcx Block<'tcx>>,
+}
+
+/// Returns the parts of the block that need to be wrapped in `unsafe` blocks of their own.
+fn unsafe_parts<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    unsafe_stmts: &[bool],
+    unsafe_tail: bool,
+) -> Option<Vec<UnsafePart<'tcx>>> {
+    let unsafe_stmts = stmts
+        .iter()
+        .zip(unsafe_stmts)
+        .filter(|&(_, is_unsafe)| *is_unsafe)
+        .map(|(stmt, _)| match stmt.kind {
+            StmtKind::Let(&LetStmt {
+                init: Some(init), els, ..
+            }) if !els.is_some_and(|els| contains_unsafe_op(cx, els)) => Some((init, els)),
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some((e, None)),
+            _ => None,
+        });
+    let tail = block.expr.filter(|_| unsafe_tail).map(|tail| Some((tail, None)));
+    unsafe_stmts
+        .chain(tail)
+        .map(|part| {
+            let (expr, els) = part?;
+            // The parts of macro calls can't be moved, and nested blocks would need to be
+            // narrowed on their own.
+            if !expr.span.eq_ctxt(block.span) || contains_unsafe_block(cx, expr) {
+                return None;
+            }
+            let operand = sole_unsafe_operand(cx, expr)
+                .or_else(|| unsafe_chain_prefix(cx, expr))
+                .unwrap_or(expr);
+            if matches!(operand.kind, ExprKind::Block(..)) {
+                return None;
+            }
+            Some(UnsafePart { expr, operand, els })
+        })
+        .collect()
+}
+
+/// Adds the replacements moving the `// SAFETY:` comment above the block to the lines of the new
+/// `unsafe` blocks to `sugg`, so that they stay documented. The comment is duplicated if the new
+/// blocks are on several lines.
+fn relocate_safety_comment(
+    cx: &LateContext<'_>,
+    block: &Block<'_>,
+    operands: impl IntoIterator<Item = Span>,
+    sugg: &mut Vec<(Span, String)>,
+) {
+    let Some(start) = block_safety_comment_start(cx, block.span) else {
+        return;
+    };
+    let block_line = first_line_of_span(cx, block.span).shrink_to_lo();
+    let comment_span = block_line.with_lo(start);
+    let Some(comment) = snippet_opt(cx, comment_span) else {
+        return;
+    };
+    let comment: Vec<_> = comment.trim_end().lines().map(str::trim_start).collect();
+
+    let mut lines: Vec<_> = operands
+        .into_iter()
+        .map(|operand| first_line_of_span(cx, operand).shrink_to_lo())
+        .collect();
+    lines.dedup();
+    // New blocks on the line of the old one are still documented by the comment in place.
+    if !lines.contains(&block_line) {
+        sugg.push((comment_span, String::new()));
+    }
+    for line in lines.into_iter().filter(|line| *line != block_line) {
+        let indent = snippet_indent(cx, line).unwrap_or_default();
+        let comment = format!("{}\n{indent}", comment.join(&format!("\n{indent}")));
+        // Replacements can't overlap, so the comment is joined with one starting or ending on the
+        // line if there is one.
+        if let Some((_, replacement)) = sugg.iter_mut().find(|(span, _)| span.lo() == line.lo()) {
+            replacement.insert_str(0, &comment);
+        } else if let Some((_, replacement)) = sugg.iter_mut().find(|(span, _)| span.hi() == line.lo()) {
+            replacement.push_str(&comment);
+        } else {
+            sugg.push((line, comment));
+        }
+    }
+}
+
+fn narrowing_msg<'tcx>(cx: &LateContext<'tcx>, block: &Block<'_>, parts: &[UnsafePart<'tcx>]) -> &'static str {
+    match parts {
+        [part] if part.operand.hir_id == part.expr.hir_id => match cx.tcx.parent_hir_node(part.expr.hir_id) {
+            Node::LetStmt(local) if matches!(local.pat.kind, PatKind::Wild) => {
+                "consider wrapping only the discarded expression in an `unsafe` block"
+            },
+            Node::LetStmt(_) => "consider wrapping only the initializer in an `unsafe` block",
+            _ if block.expr.is_some_and(|tail| tail.hir_id == part.expr.hir_id) => {
+                "consider wrapping only the tail expression in an `unsafe` block"
+            },
+            _ if unsafe_ops(cx, part.expr).len() == 1 => {
+                "consider wrapping only the unsafe operation in an `unsafe` block"
+            },
+            _ => "consider wrapping only the statement with unsafe operations in an `unsafe` block",
+        },
+        [part] if unsafe_ops(cx, part.operand).len() == 1 => {
+            "consider wrapping only the unsafe operation in an `unsafe` block"
+        },
+        [_] => "consider wrapping only the unsafe method calls in an `unsafe` block",
+        _ if parts.iter().all(|part| unsafe_ops(cx, part.operand).len() == 1) => {
+            "consider wrapping each unsafe operation in its own `unsafe` block"
+        },
+        _ => "consider wrapping only the statements with unsafe operations in `unsafe` blocks",
+    }
+}
+
+fn binds_ordered_drop_value<'tcx>(cx: &LateContext<'tcx>, stmt: &Stmt<'tcx>) -> bool {
+    matches!(stmt.kind, StmtKind::Let(local)
+        if !matches!(local.pat.kind, PatKind::Wild) && needs_ordered_drop(cx, cx.typeck_results().pat_ty(local.pat)))
+}
+
+/// Checks for `let _ = ..;`, which doesn't bind anything and thus can be freely moved out of a
+/// block.
+fn is_discard(stmt: &Stmt<'_>) -> bool {
+    matches!(stmt.kind, StmtKind::Let(local) if matches!(local.pat.kind, PatKind::Wild))
+}
+
+/// Returns the replacements turning the `unsafe` block into a plain block. If the block is used
+/// as a statement and doesn't introduce any names, its braces are removed as well.
+fn unwrap_block_parts(cx: &LateContext<'_>, block: &Block<'_>) -> Vec<(Span, String)> {
+    if let Node::Expr(expr) = cx.tcx.parent_hir_node(block.hir_id)
+        && let Node::Stmt(outer_stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+        && block
+            .stmts
+            .iter()
+            .all(|stmt| is_discard(stmt) || matches!(stmt.kind, StmtKind::Expr(_) | StmtKind::Semi(_)))
+        && block.stmts.iter().all(|stmt| stmt.span.eq_ctxt(block.span))
+        && let [first, .., last] | [first @ last] = block.stmts
+    {
+        // Without a tail expression the statements keep their own semicolons, so the one after
+        // the block can go as well.
+        let (last, end) = match block.expr {
+            Some(tail) => (tail.span, block.span.hi()),
+            None => (last.span, outer_stmt.span.hi()),
+        };
+        vec![
+            (block.span.with_hi(first.span.lo()), String::new()),
+            (block.span.with_lo(last.hi()).with_hi(end), String::new()),
+        ]
+    } else {
+        vec![(unsafe_keyword_span(cx, block), String::new())]
+    }
+}
+
+/// Checks the body of an `unsafe` function as an implicit `unsafe` block, for use with
+/// `minimal-unsafe-block-check-unsafe-fn-bodies`.
+fn check_unsafe_fn_body<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Block<'tcx>) {
+    let ops = implicitly_unsafe_ops(cx, body);
+    if ops.is_empty() {
+        return;
+    }
+    let safe_spans: Vec<_> = body
+        .stmts
+        .iter()
+        .filter(|stmt| !matches!(stmt.kind, StmtKind::Item(_)) && implicitly_unsafe_ops(cx, *stmt).is_empty())
+        .map(|stmt| walk_span_to_context(stmt.span, body.span.ctxt()).unwrap_or(stmt.span))
+        .collect();
+    let safe_tail = body.expr.filter(|tail| implicitly_unsafe_ops(cx, *tail).is_empty());
+    if safe_spans.is_empty() && safe_tail.is_none() {
+        return;
+    }
+    let reason = Reason::UnsafeFnBody;
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        body.hir_id,
+        body.span,
+        reason.msg(false),
+        |diag| {
+            for op in &ops {
+                diag.span_label(op.span, describe_unsafe_op(cx, op));
+            }
+            label_safe_stmts(diag, &safe_spans, "this statement doesn't need the `unsafe` context");
+            if let Some(tail) = safe_tail {
+                diag.span_label(tail.span, "this expression doesn't need the `unsafe` context");
+            }
+            diag.note("the `unsafe_op_in_unsafe_fn` lint is allowed here, as it is by default before edition 2024");
+            reason.add_help(diag);
+        },
+    );
+}
+
+/// Checks that at most three quarters of the statements in the body of an `unsafe` function are
+/// safe, for `mostly_safe_unsafe_fn`.
+fn check_mostly_safe_fn<'tcx>(cx: &LateContext<'tcx>, def_id: LocalDefId, body: &'tcx Block<'tcx>) {
+    let units: Vec<bool> = body
+        .stmts
+        .iter()
+        .filter(|stmt| !matches!(stmt.kind, StmtKind::Item(_)))
+        .map(|stmt| contains_unsafe_op(cx, stmt))
+        .chain(body.expr.map(|tail| contains_unsafe_op(cx, tail)))
+        .collect();
+    let safe = units.iter().filter(|is_unsafe| !**is_unsafe).count();
+    if units.len() < MIN_MOSTLY_SAFE_FN_STMTS || safe * 4 < units.len() * 3 {
+        return;
+    }
+    span_lint_hir_and_then(
+        cx,
+        MOSTLY_SAFE_UNSAFE_FN,
+        cx.tcx.local_def_id_to_hir_id(def_id),
+        cx.tcx.def_span(def_id),
+        "this `unsafe` function consists mostly of safe code",
+        |diag| {
+            label_unsafe_ops(cx, diag, body);
+            if safe == units.len() {
+                diag.note(format!("none of its {safe} statements perform an unsafe operation"));
+                diag.help("if callers don't have to uphold any safety requirements, consider making the function safe");
+            } else {
+                diag.note(format!(
+                    "{safe} of its {} statements don't perform any unsafe operation",
+                    units.len()
+                ));
+                diag.help(
+                    "if callers don't have to uphold any safety requirements, \
+                    consider making the function safe and wrapping its unsafe operations in `unsafe` blocks",
+                );
+            }
+            diag.help("otherwise, consider documenting the requirements in a `# Safety` section");
+        },
+    );
+}
+
+/// Returns the unsafe operations which aren't covered by an explicit `unsafe` block.
+fn implicitly_unsafe_ops<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> Vec<&'tcx Expr<'tcx>> {
+    let mut ops = Vec::new();
+    for_each_expr(cx, node, |expr| {
+        if let ExprKind::Block(block, _) = expr.kind
+            && block.rules != BlockCheckMode::DefaultBlock
+        {
+            return ControlFlow::<(), _>::Continue(Descend::No);
+        }
+        if is_unsafe_op(cx, expr) {
+            ops.push(expr);
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    ops
+}
+
+/// Checks that the operands of a block's sole unsafe operation are computed outside of it, for
+/// use with `minimal-unsafe-block-strict`.
+fn check_safe_operands<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, stmts: &[&'tcx Stmt<'tcx>]) -> bool {
+    let expr = match (stmts, block.expr) {
+        ([], Some(tail)) => tail,
+        ([stmt], None) if let StmtKind::Semi(expr) | StmtKind::Expr(expr) = stmt.kind => expr,
+        _ => return false,
+    };
+    if !is_minimal_expr(cx, expr) {
+        return false;
+    }
+    let operands = safe_operands(cx, expr);
+    if operands.is_empty() {
+        return false;
+    }
+    let reason = Reason::SafeOperands;
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        block.span,
+        reason.msg(false),
+        |diag| {
+            for operand in &operands {
+                diag.span_label(operand.span, "this can be computed before the `unsafe` block");
+            }
+            reason.add_help(diag);
+        },
+    );
+    true
+}
+
+/// Returns the operands of an expression accepted by `is_minimal_expr` which are computed by safe
+/// code, as in `f(compute())` or `*ptr = compute()`. Plain variables, literals and the like are
+/// skipped.
+fn safe_operands<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Vec<&'tcx Expr<'tcx>> {
+    let mut operands = Vec::new();
+    loop {
+        if is_unsafe_op(cx, expr) {
+            match expr.kind {
+                ExprKind::Call(_, args) => operands.extend(args),
+                ExprKind::MethodCall(_, receiver, args, _) => {
+                    operands.push(receiver);
+                    operands.extend(args);
+                },
+                ExprKind::Unary(UnOp::Deref, ptr) => operands.push(ptr),
+                _ => {},
+            }
+            break;
+        }
+        match expr.kind {
+            ExprKind::AddrOf(_, _, place) | ExprKind::Field(place, _) => expr = place,
+            ExprKind::Index(place, value, _)
+            | ExprKind::Assign(place, value, _)
+            | ExprKind::AssignOp(_, place, value) => {
+                operands.push(value);
+                expr = place;
+            },
+            _ => break,
+        }
+    }
+    operands.retain(|operand| !is_trivial_operand(operand) && !contains_unsafe_op(cx, *operand));
+    operands.sort_by_key(|operand| operand.span.lo());
+    operands
+}
+
+/// Checks whether computing the expression outside of the `unsafe` block gains nothing, e.g.
+/// because it is a variable or literal.
+fn is_trivial_operand(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) | ExprKind::Closure(_) | ExprKind::ConstBlock(_) => true,
+        ExprKind::AddrOf(_, _, inner)
+        | ExprKind::Field(inner, _)
+        | ExprKind::Cast(inner, _)
+        | ExprKind::Unary(_, inner)
+        | ExprKind::DropTemps(inner) => is_trivial_operand(inner),
+        ExprKind::Tup(elements) | ExprKind::Array(elements) => elements.iter().all(is_trivial_operand),
+        _ => false,
+    }
+}
+
+/// Checks that the block is nested in fewer than `max` other `unsafe` blocks, for use with
+/// `max-unsafe-block-nesting`.
+fn check_nesting<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, max: usize) -> bool {
+    let outer: Vec<_> = cx
+        .tcx
+        .hir()
+        .parent_iter(block.hir_id)
+        .take_while(|(_, node)| !matches!(node, Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_)))
+        .filter_map(|(_, node)| match node {
+            Node::Block(outer) if outer.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) => Some(outer),
+            _ => None,
+        })
+        .collect();
+    let depth = outer.len() + 1;
+    if depth <= max {
+        return false;
+    }
+    let reason = Reason::NestedTooDeeply { depth, max };
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        block.span,
+        reason.msg(false),
+        |diag| {
+            for outer in outer {
+                diag.span_label(unsafe_keyword_span(cx, outer), "nested in this `unsafe` block");
+            }
+            if !note_no_unsafe_ops(cx, diag, block) {
+                reason.add_help(diag);
+            }
+        },
+    );
+    true
+}
+
+/// Points out blocks without any unsafe operation, which are only linted for their size or nesting
+/// if `unused_unsafe` is allowed. Returns whether the block is one of them.
+fn note_no_unsafe_ops<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) -> bool {
+    if contains_unsafe_op(cx, block) {
+        return false;
+    }
+    diag.note("the `unsafe` block doesn't contain any unsafe operations, but `unused_unsafe` is allowed here");
+    diag.help("consider removing the `unsafe` block");
+    true
+}
+
+/// Checks that the block spans at most `max` lines, for use with `unsafe-block-max-lines`.
+fn check_line_count<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, max: usize) -> bool {
+    let source_map = cx.sess().source_map();
+    let lines = source_map.lookup_char_pos(block.span.hi()).line - source_map.lookup_char_pos(block.span.lo()).line + 1;
+    if lines <= max {
+        return false;
+    }
+    let reason = Reason::TooManyLines { lines, max };
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        block.span,
+        reason.msg(false),
+        |diag| {
+            if !note_no_unsafe_ops(cx, diag, block) {
+                reason.add_help(diag);
+            }
+        },
+    );
+    true
+}
+
+/// Checks that the block contains at most `max` unsafe operations, for use with
+/// `minimal-unsafe-block-require-single-op` and `max-unsafe-operations-per-block`.
+fn check_op_count<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    max: usize,
+    counts: impl Fn(&'tcx Expr<'tcx>) -> bool,
+) -> bool {
+    let mut ops = unsafe_ops(cx, block);
+    ops.retain(|op| counts(op));
+    if ops.len() <= max {
+        return false;
+    }
+    let reason = Reason::TooManyOps { ops: ops.len(), max };
+    let ffi = is_ffi_block(cx, block);
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        block.span,
+        reason.msg(ffi),
+        |diag| {
+            for op in &ops {
+                diag.span_label(op.span, describe_unsafe_op(cx, op));
+            }
+            reason.add_help(diag);
+            help_safe_wrapper(cx, diag, block);
+            if ffi {
+                help_ffi_config(diag);
+            }
+            note_safety_comments(cx, diag, block);
+        },
+    );
+    true
+}
+
+fn check_tail<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    tail: &'tcx Expr<'tcx>,
+    suggestions: UnsafeBlockSuggestions,
+    region_notes: bool,
+) -> bool {
+    let Some(kind) = non_minimal_tail(cx, tail) else {
+        return false;
+    };
+    let reason = if is_unsafe_block(tail) && tail.span.eq_ctxt(block.span) {
+        Reason::WrapsUnsafeBlock
+    } else if is_whole_fn_body(cx, block) {
+        Reason::WholeFnBody
+    } else {
+        Reason::SafeTail(kind)
+    };
+    let ffi = reason != Reason::WrapsUnsafeBlock && is_ffi_block(cx, block);
+    span_lint_hir_and_then(
+        cx,
+        MINIMAL_UNSAFE_BLOCK,
+        block.hir_id,
+        block.span,
+        reason.msg(ffi),
+        |diag| {
+            match reason {
+                Reason::WrapsUnsafeBlock => {
+                    let mut sugg = vec![
+                        (block.span.until(tail.span), String::new()),
+                        (tail.span.shrink_to_hi().to(block.span.shrink_to_hi()), String::new()),
+                    ];
+                    let mut app = if removes_comments(cx, &sugg) || block.span.from_expansion() {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    reindent_sugg(cx, block.span, &mut sugg, &mut app);
+                    diag.multipart_suggestion("remove the outer `unsafe` block", sugg, app);
+                },
+                Reason::WholeFnBody => {
+                    label_unsafe_ops(cx, diag, block);
+                    diag.note(format!("the unsafe operations are part of {}", kind.describe()));
+                    note_unsafe_context(cx, diag, block, &unsafe_ops(cx, block));
+                    reason.add_help(diag);
+                    suggest_whole_body_rewrite(cx, diag, block);
+                    help_safe_wrapper(cx, diag, block);
+                    if ffi {
+                        help_ffi_config(diag);
+                    }
+                    note_safety_comments(cx, diag, block);
+                },
+                _ => {
+                    label_unsafe_ops(cx, diag, block);
+                    note_unsafe_context(cx, diag, block, &unsafe_ops(cx, block));
+                    label_safe_chain_parts(cx, diag, tail);
+                    suggest_narrowing_tail(cx, diag, block, tail, kind);
+                    help_safe_wrapper(cx, diag, block);
+                    if ffi {
+                        help_ffi_config(diag);
+                    }
+                    note_safety_comments(cx, diag, block);
+                },
+            }
+            if region_notes {
+                note_regions(cx, diag, block);
+            }
+            restrict_suggestions(diag, suggestions);
+        },
+    );
+    true
+}
+
+/// Labels the safe calls at the end of a method chain with unsafe calls, and the safe call at its
+/// start, as in `v.as_ptr().add(1).read().to_string()`.
+fn label_safe_chain_parts<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, tail: &'tcx Expr<'tcx>) {
+    let Some(prefix) = unsafe_chain_prefix(cx, tail) else {
+        return;
+    };
+    let mut link = tail;
+    let mut safe_calls = 0;
+    let mut first_safe = tail.span;
+    while link.hir_id != prefix.hir_id
+        && let ExprKind::MethodCall(segment, receiver, ..) = link.kind
+    {
+        safe_calls += 1;
+        first_safe = segment.ident.span;
+        link = receiver;
+    }
+    let label = if safe_calls == 1 {
+        "this call is safe and can be outside of the `unsafe` block"
+    } else {
+        "these calls are safe and can be outside of the `unsafe` block"
+    };
+    diag.span_label(first_safe.with_hi(tail.span.hi()), label);
+
+    // The receiver of the first unsafe method call.
+    let mut root = prefix;
+    while let ExprKind::MethodCall(_, receiver, ..) = root.kind
+        && contains_unsafe_op(cx, receiver)
+    {
+        root = receiver;
+    }
+    if let ExprKind::MethodCall(_, receiver, ..) = root.kind
+        && matches!(receiver.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+        && receiver.span.eq_ctxt(tail.span)
+    {
+        diag.span_label(
+            receiver.span,
+            "this call is safe and can be made before the `unsafe` block",
+        );
+    }
+}
+
+/// Suggests moving the `unsafe` block into the tail expression, around its unsafe parts.
+fn suggest_narrowing_tail<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    block: &'tcx Block<'tcx>,
+    tail: &'tcx Expr<'tcx>,
+    kind: TailKind,
+) {
+    let operands = if !block.span.eq_ctxt(tail.span) {
+        None
+    } else if let Some(operand) = sole_unsafe_operand(cx, tail).or_else(|| unsafe_chain_prefix(cx, tail)) {
+        Some(vec![operand])
+    } else {
+        unsafe_elements(cx, tail).or_else(|| unsafe_condition_parts(cx, tail))
+    };
+    let Some(operands) = operands else {
+        if let Some(form) = narrow_form(cx, tail) {
+            diag.help(format!("consider moving the `unsafe` block inward, as in `{form}`"));
+        } else {
+            diag.help("consider moving the `unsafe` block inward, around the unsafe operations");
+        }
+        return;
+    };
+    let mut app = if operands.iter().all(|operand| {
+        matches!(operand.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)) && !in_macro_context(cx, block, operand)
+    }) {
+        Applicability::MachineApplicable
+    } else {
+        // Moving a place expression into a block turns it into a value.
+        Applicability::MaybeIncorrect
+    };
+    let mut sugg = vec![
+        (block.span.until(tail.span), String::new()),
+        (tail.span.shrink_to_hi().to(block.span.shrink_to_hi()), String::new()),
+    ];
+    if removes_comments(cx, &sugg) {
+        app = Applicability::MaybeIncorrect;
+    }
+    for operand in &operands {
+        sugg.push(wrap_in_unsafe(cx, operand, block.span.ctxt(), Some(block), &mut app));
+    }
+    reindent_sugg(cx, block.span, &mut sugg, &mut app);
+    let msg = match &operands[..] {
+        [operand] if unsafe_ops(cx, *operand).len() == 1 => {
+            "consider moving the `unsafe` block inward, around the unsafe operation"
+        },
+        _ if kind == TailKind::If => "consider moving the `unsafe` block into the `if` condition",
+        [_] if !matches!(kind, TailKind::Array | TailKind::Tuple) => {
+            "consider moving the `unsafe` block inward, around the unsafe method calls"
+        },
+        [_] => "consider moving the `unsafe` block inward, around the unsafe element",
+        _ => "consider moving the `unsafe` block inward, around each unsafe element",
+    };
+    diag.multipart_suggestion(msg, sugg, app);
+}
+
+/// Renders the tail expression with the `unsafe` block moved one level inward, around each of its
+/// operands with unsafe operations, as in `if unsafe { f() == g() } { .. }`. Used as an example for
+/// the user when a precise suggestion can't be made.
+fn narrow_form<'tcx>(cx: &LateContext<'tcx>, tail: &'tcx Expr<'tcx>) -> Option<String> {
+    let wrap = |expr: &'tcx Expr<'tcx>| {
+        let snip = snippet(cx, expr.span, "..");
+        if contains_unsafe_op(cx, expr) {
+            format!("unsafe {{ {snip} }}")
+        } else {
+            snip.into_owned()
+        }
+    };
+    let wrap_all = |exprs: &'tcx [Expr<'tcx>]| exprs.iter().map(wrap).collect::<Vec<_>>().join(", ");
+    let form = match tail.kind {
+        ExprKind::If(cond, then, els)
+            if !contains_unsafe_op(cx, then)
+                && !els.is_some_and(|els| contains_unsafe_op(cx, els))
+                && for_each_expr_without_closures(cond, |e| {
+                    if matches!(e.kind, ExprKind::Let(..)) {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                })
+                .is_none() =>
+        {
+            let els = if els.is_some() { " else { .. }" } else { "" };
+            format!("if {} {{ .. }}{els}", wrap(cond))
+        },
+        ExprKind::Call(callee, args) if !contains_unsafe_op(cx, callee) => {
+            format!("{}({})", snippet(cx, callee.span, ".."), wrap_all(args))
+        },
+        ExprKind::MethodCall(segment, receiver, args, _) => {
+            format!("{}.{}({})", wrap(receiver), segment.ident, wrap_all(args))
+        },
+        ExprKind::Array(elems) => format!("[{}]", wrap_all(elems)),
+        ExprKind::Tup([elem]) => format!("({},)", wrap(elem)),
+        ExprKind::Tup(elems) => format!("({})", wrap_all(elems)),
+        _ => return None,
+    };
+    (!form.contains('\n') && form.len() <= MAX_FORM_LEN).then_some(form)
+}
+
+/// Drops or downgrades the suggestions of a diagnostic according to
+/// `minimal-unsafe-block-suggestions`. The messages of dropped suggestions are kept as help
+/// messages.
+fn restrict_suggestions(diag: &mut Diag<'_, ()>, mode: UnsafeBlockSuggestions) {
+    let Suggestions::Enabled(suggestions) = &mut diag.suggestions else {
+        return;
+    };
+    let dropped: Vec<_> = match mode {
+        UnsafeBlockSuggestions::All => return,
+        UnsafeBlockSuggestions::MaybeIncorrect => {
+            for suggestion in suggestions.iter_mut() {
+                if suggestion.applicability == Applicability::MachineApplicable {
+                    suggestion.applicability = Applicability::MaybeIncorrect;
+                }
+            }
+            return;
+        },
+        UnsafeBlockSuggestions::MachineApplicableOnly => {
+            let (kept, dropped) = mem::take(suggestions)
+                .into_iter()
+                .partition(|suggestion| suggestion.applicability == Applicability::MachineApplicable);
+            *suggestions = kept;
+            dropped
+        },
+        UnsafeBlockSuggestions::Never => mem::take(suggestions),
+    };
+    for suggestion in dropped {
+        if let DiagMessage::Str(msg) = suggestion.msg {
+            diag.help(msg);
+        }
+    }
+}
+
+/// Returns the part of the expression to wrap in an `unsafe` block if it contains a single
+/// unsafe operation. Place projections of the operation, as in `(*ptr).field`, are included.
+fn sole_unsafe_operand<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let [op] = unsafe_ops(cx, expr)[..] else {
+        return None;
+    };
+    // Operations in nested `unsafe` blocks are already covered by them.
+    if op.hir_id != expr.hir_id
+        && cx
+            .tcx
+            .hir()
+            .parent_iter(op.hir_id)
+            .take_while(|&(id, _)| id != expr.hir_id)
+            .any(|(_, node)| matches!(node, Node::Block(inner) if inner.rules != BlockCheckMode::DefaultBlock))
+    {
+        return None;
+    }
+    let operand = place_operand(cx, op, expr.hir_id);
+    operand.span.eq_ctxt(expr.span).then_some(operand)
+}
+
+/// Extends the unsafe operation to the place projections and assignments it's part of, as in
+/// `(*ptr).field` or `*ptr = value`, stopping at `outer`.
+fn place_operand<'tcx>(cx: &LateContext<'tcx>, op: &'tcx Expr<'tcx>, outer: HirId) -> &'tcx Expr<'tcx> {
+    let mut operand = op;
+    for (_, node) in cx.tcx.hir().parent_iter(op.hir_id) {
+        match node {
+            Node::Expr(parent)
+                if operand.hir_id != outer
+                    && let ExprKind::AddrOf(_, _, place)
+                    | ExprKind::Field(place, _)
+                    | ExprKind::Index(place, ..)
+                    | ExprKind::Assign(place, ..)
+                    | ExprKind::AssignOp(_, place, _) = parent.kind
+                    && place.hir_id == operand.hir_id =>
+            {
+                operand = parent;
+            },
+            _ => break,
+        }
+    }
+    operand
+}
+
+/// Returns the receiver of the safe method calls at the end of a method chain if it contains all
+/// the unsafe operations of the chain, as in `x.unsafe_m().unsafe_m().safe_m()`.
+fn unsafe_chain_prefix<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let mut prefix = expr;
+    while let ExprKind::MethodCall(_, receiver, args, _) = prefix.kind
+        && !is_unsafe_op(cx, prefix)
+        && !args.iter().any(|arg| contains_unsafe_op(cx, arg))
+    {
+        prefix = receiver;
+    }
+    (prefix.hir_id != expr.hir_id
+        && prefix.span.eq_ctxt(expr.span)
+        && contains_unsafe_op(cx, prefix)
+        && !contains_unsafe_block(cx, prefix))
+    .then_some(prefix)
+}
+
+/// Checks whether the code is part of the body of an `unsafe` function, including the closures
+/// defined in it.
+fn is_in_unsafe_fn(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    let owner = cx
+        .tcx
+        .typeck_root_def_id(cx.tcx.hir().enclosing_body_owner(hir_id).to_def_id());
+    matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+        && cx.tcx.fn_sig(owner).skip_binder().safety() == Safety::Unsafe
+}
+
+/// Checks whether the expression is a method chain with an unsafe call among its links, as in
+/// `ptr.add(1).read().to_string()` or `unsafe_fn().to_string()`.
+fn is_unsafe_method_chain<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    let mut link = expr;
+    while let ExprKind::MethodCall(_, receiver, ..) = link.kind {
+        if unsafe_op_kind(cx, link) == Some(UnsafeOperationKind::UnsafeCall) {
+            return true;
+        }
+        link = receiver;
+    }
+    link.hir_id != expr.hir_id && unsafe_op_kind(cx, link) == Some(UnsafeOperationKind::UnsafeCall)
+}
+
+/// Returns the parts to wrap of the elements of an array or tuple with unsafe operations, as in
+/// `[unsafe_fn(0), 1, unsafe_fn(2)]`, if each of them can be narrowed to a single `unsafe` block.
+fn unsafe_elements<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    let (ExprKind::Array(elems) | ExprKind::Tup(elems)) = expr.kind else {
+        return None;
+    };
+    elems
+        .iter()
+        .filter(|elem| contains_unsafe_op(cx, *elem))
+        .map(|elem| {
+            if elem.span.eq_ctxt(expr.span) {
+                sole_unsafe_operand(cx, elem).or_else(|| unsafe_chain_prefix(cx, elem))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the parts to wrap of the condition of an `if` expression whose branches are safe. The
+/// operands of `&&` chains and the scrutinees of `let` expressions are narrowed separately, as in
+/// `if let Some(x) = unsafe_fn(0) && unsafe_fn(x) > 0`.
+fn unsafe_condition_parts<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    let ExprKind::If(cond, then, els) = expr.kind else {
+        return None;
+    };
+    if contains_unsafe_op(cx, then) || els.is_some_and(|els| contains_unsafe_op(cx, els)) {
+        return None;
+    }
+    let mut leaves = Vec::new();
+    let mut pending = vec![cond];
+    while let Some(e) = pending.pop() {
+        match e.kind {
+            ExprKind::DropTemps(inner) => pending.push(inner),
+            ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => pending.extend([rhs, lhs]),
+            ExprKind::Let(let_expr) => pending.push(let_expr.init),
+            _ if contains_unsafe_op(cx, e) => leaves.push(e),
+            _ => {},
+        }
+    }
+    leaves
+        .into_iter()
+        .map(|leaf| {
+            if leaf.span.eq_ctxt(expr.span) {
+                sole_unsafe_operand(cx, leaf).or_else(|| unsafe_chain_prefix(cx, leaf))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the replacement wrapping the operand in an `unsafe` block, parenthesized if the block
+/// would otherwise change how the code around it parses. The contents of `removed`, a block the
+/// suggestion removes the braces of, take its place.
+///
+/// A multi-line block whose value is unit takes the semicolon of the statement inside, as
+/// `clippy::semicolon_if_nothing_returned` would ask for it there.
+fn wrap_in_unsafe<'tcx>(
+    cx: &LateContext<'tcx>,
+    operand: &'tcx Expr<'tcx>,
+    ctxt: SyntaxContext,
+    removed: Option<&Block<'_>>,
+    app: &mut Applicability,
+) -> (Span, String) {
+    let (snip, _) = snippet_with_context(cx, operand.span, ctxt, "..", app);
+    if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(operand.hir_id)
+        && let StmtKind::Semi(_) = stmt.kind
+        && stmt.span.eq_ctxt(operand.span)
+        && snip.contains('\n')
+        && cx.typeck_results().expr_ty(operand).is_unit()
+    {
+        (operand.span.with_hi(stmt.span.hi()), format!("unsafe {{ {snip}; }}"))
+    } else if wrapped_needs_parens(cx, operand, removed) {
+        (operand.span, format!("(unsafe {{ {snip} }})"))
+    } else {
+        (operand.span, format!("unsafe {{ {snip} }}"))
+    }
+}
+
+/// Checks whether an `unsafe` block in place of the expression has to be parenthesized. A block
+/// at the start of a statement, a tail expression or a match arm ends the expression there, so
+/// `unsafe { f() } as usize;` parses as a block followed by a separate expression, while method
+/// calls, field accesses and `?` may follow it. The initializer of a `let`-`else` can't end with a
+/// block either.
+fn wrapped_needs_parens(cx: &LateContext<'_>, expr: &Expr<'_>, removed: Option<&Block<'_>>) -> bool {
+    let is_removed = |block: &Block<'_>| removed.is_some_and(|removed| removed.hir_id == block.hir_id);
+    let mut child = expr.hir_id;
+    let mut child_span = expr.span;
+    let (mut leftmost, mut rightmost) = (true, true);
+    // Whether the first expression the block begins is something other than a method call, field
+    // access or `?`, which may directly follow a block.
+    let mut continued = None;
+    for (id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Block(block) if is_removed(block) => {},
+            Node::Expr(parent) if matches!(parent.kind, ExprKind::Block(block, _) if is_removed(block)) => {
+                child = id;
+                child_span = parent.span;
+            },
+            Node::Expr(parent) => {
+                if leftmost
+                    && continued.is_none()
+                    && parent.span.lo() == child_span.lo()
+                    && parent.span.hi() != child_span.hi()
+                {
+                    continued = Some(
+                        !matches!(parent.kind, ExprKind::MethodCall(..) | ExprKind::Field(..))
+                            && !parent.span.is_desugaring(DesugaringKind::QuestionMark),
+                    );
+                }
+                leftmost &= parent.span.lo() == child_span.lo();
+                rightmost &= parent.span.hi() == child_span.hi();
+                if !leftmost && !rightmost {
+                    return false;
+                }
+                child = id;
+                child_span = parent.span;
+            },
+            Node::Stmt(_) | Node::Block(_) => return leftmost && continued == Some(true),
+            Node::Arm(arm) => return leftmost && continued == Some(true) && arm.body.hir_id == child,
+            Node::LetStmt(local) => return rightmost && local.els.is_some(),
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Joins the replacements within `span` into a single replacement of `span` whose lines are
+/// reindented to the indentation of its first line, for suggestions that move code to an outer
+/// level of nesting. Multi-line literals can't be reindented without changing them, so the
+/// replacements are left as they are and the applicability is downgraded instead.
+fn reindent_sugg(cx: &LateContext<'_>, span: Span, sugg: &mut Vec<(Span, String)>, app: &mut Applicability) {
+    let Some(src) = snippet_opt(cx, span) else {
+        *app = Applicability::MaybeIncorrect;
+        return;
+    };
+    if !src.contains('\n') {
+        return;
+    }
+    if has_multiline_literal(&src) {
+        *app = Applicability::MaybeIncorrect;
+        return;
+    }
+
+    let (mut inner, mut outer): (Vec<_>, Vec<_>) = sugg.drain(..).partition(|(part, _)| span.contains(*part));
+    inner.sort_by_key(|(part, _)| part.lo());
+    let mut spliced = String::new();
+    let mut pos = span.lo();
+    for (part, replacement) in inner {
+        spliced.push_str(&src[(pos - span.lo()).to_usize()..(part.lo() - span.lo()).to_usize()]);
+        spliced.push_str(&replacement);
+        pos = part.hi();
+    }
+    spliced.push_str(&src[(pos - span.lo()).to_usize()..]);
+    let indent = indent_of(cx, span);
+    outer.push((span, reindent_multiline(spliced.into(), true, indent).into_owned()));
+    *sugg = outer;
+}
+
+/// Checks whether rewriting the operand involves code from a macro expansion, either because the
+/// block is part of a macro body or because the operand is passed to a macro call, as in
+/// `vec![unsafe_fn(0)]`. The rewritten code may then not be what the macro ends up expanding to.
+fn in_macro_context(cx: &LateContext<'_>, block: &Block<'_>, operand: &Expr<'_>) -> bool {
+    block.span.from_expansion()
+        || cx
+            .tcx
+            .hir()
+            .parent_id_iter(operand.hir_id)
+            .take_while(|&id| id != block.hir_id)
+            .any(|id| cx.tcx.hir().span(id).from_expansion())
+}
+
+/// Checks for literals spanning several lines, which can't be reindented without changing them.
+fn has_multiline_literal(src: &str) -> bool {
+    let mut pos = 0;
+    tokenize(src).any(|token| {
+        let text = &src[pos..pos + token.len as usize];
+        pos += token.len as usize;
+        matches!(token.kind, TokenKind::Literal { .. }) && text.contains('\n')
+    })
+}
+
+/// Checks whether applying the suggestion would delete comments.
+fn removes_comments(cx: &LateContext<'_>, sugg: &[(Span, String)]) -> bool {
+    sugg.iter()
+        .any(|(span, replacement)| replacement.is_empty() && span_contains_comment(cx.sess().source_map(), *span))
+}
+
+/// Checks if the block makes up the whole body of a function, as in `fn f() { unsafe { .. } }`.
+fn is_whole_fn_body(cx: &LateContext<'_>, block: &Block<'_>) -> bool {
+    if let Some(body_id) = cx.enclosing_body
+        && matches!(
+            cx.tcx.def_kind(cx.tcx.hir().body_owner_def_id(body_id)),
+            DefKind::Fn | DefKind::AssocFn
+        )
+        && let ExprKind::Block(body_block, _) = cx.tcx.hir().body(body_id).value.kind
+        && let Some(outer) = match (body_block.stmts, body_block.expr) {
+            ([], Some(tail)) => Some(tail),
+            (
+                [
+                    Stmt {
+                        kind: StmtKind::Expr(expr) | StmtKind::Semi(expr),
+                        ..
+                    },
+                ],
+                None,
+            ) => Some(*expr),
+            _ => None,
+        }
+        && let ExprKind::Block(outer_block, _) = outer.kind
+    {
+        outer_block.hir_id == block.hir_id
+    } else {
+        false
+    }
+}
+
+/// Describes the safe code around the unsafe operations in the tail expression, if there is any.
+fn non_minimal_tail<'tcx>(cx: &LateContext<'tcx>, tail: &'tcx Expr<'tcx>) -> Option<TailKind> {
+    if tail.span.from_expansion()
+        || is_minimal_expr(cx, tail)
+        || !contains_unsafe_op(cx, tail)
+        || any_temporaries_need_ordered_drop(cx, tail)
+    {
+        return None;
+    }
+
+    Some(match tail.kind {
+        ExprKind::Block(..) => TailKind::Block,
+        ExprKind::If(..) => TailKind::If,
+        ExprKind::Array(..) => TailKind::Array,
+        ExprKind::Tup(..) => TailKind::Tuple,
+        ExprKind::MethodCall(..) => TailKind::MethodCall,
+        ExprKind::Call(..) => TailKind::Call,
+        _ => TailKind::Other,
+    })
+}
+
+fn is_unsafe_block(expr: &Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Block(block, _) if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+    )
+}
+
+/// Checks whether the expression is an unsafe operation, looking through place projections and
+/// assignments such as `&(*ptr).field` or `*ptr = value`, which can't be split up without changing
+/// their meaning.
+fn is_minimal_expr<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> bool {
+    loop {
+        if is_unsafe_op(cx, expr) {
+            return true;
+        }
+        match expr.kind {
+            ExprKind::AddrOf(_, _, place)
+            | ExprKind::Field(place, _)
+            | ExprKind::Index(place, ..)
+            | ExprKind::Assign(place, ..)
+            | ExprKind::AssignOp(_, place, _) => expr = place,
+            _ => return false,
+        }
+    }
+}
+
+fn unsafe_ops<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> Vec<&'tcx Expr<'tcx>> {
+    let mut ops = Vec::new();
+    for_each_expr(cx, node, |expr| {
+        if is_unsafe_op(cx, expr) {
+            ops.push(expr);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    ops
+}
+
+fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> bool {
+    for_each_expr(cx, node, |e| {
+        if is_unsafe_block(e) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Returns the kind of unsafe operation the expression itself performs, if any.
+fn unsafe_op_kind<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<UnsafeOperationKind> {
+    if !is_unsafe_op(cx, expr) {
+        return None;
+    }
+    Some(match expr.kind {
+        ExprKind::InlineAsm(_) => UnsafeOperationKind::InlineAsm,
+        ExprKind::Field(..) => UnsafeOperationKind::UnionFieldAccess,
+        ExprKind::Unary(..) => UnsafeOperationKind::RawPointerDeref,
+        ExprKind::Path(..) => UnsafeOperationKind::StaticAccess,
+        _ => UnsafeOperationKind::UnsafeCall,
+    })
+}
+
+/// Describes an expression for which `is_unsafe_op` returned `true`, e.g. "the call to
+/// `ptr::write`".
+fn describe_unsafe_op(cx: &LateContext<'_>, expr: &Expr<'_>) -> String {
+    match expr.kind {
+        ExprKind::InlineAsm(_) => "the inline assembly".to_owned(),
+        ExprKind::Field(_, ident) => format!("the access to the union field `{ident}`"),
+        ExprKind::Unary(UnOp::Deref, _) => "the dereference of a raw pointer".to_owned(),
+        ExprKind::Call(callee, _) if let ty::FnDef(..) = cx.typeck_results().expr_ty(callee).kind() => {
+            format!("the call to `{}`", snippet(cx, callee.span, ".."))
+        },
+        ExprKind::Call(..) => "the call through an unsafe function pointer".to_owned(),
+        ExprKind::MethodCall(segment, ..) => format!("the call to `{}`", segment.ident),
+        _ => format!("the access to `{}`", snippet(cx, expr.span, "..")),
+    }
+}
+
+/// Returns the function called by the expression and whether it is called as a method.
+fn called_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(DefId, bool)> {
+    match expr.kind {
+        ExprKind::Call(callee, _) if let ty::FnDef(id, _) = *cx.typeck_results().expr_ty(callee).kind() => {
+            Some((id, false))
+        },
+        ExprKind::MethodCall(..) => cx
+            .typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .map(|id| (id, true)),
+        _ => None,
+    }
+}
+
+/// Checks if all the unsafe operations in the block are calls to foreign functions, as is
+/// common in code built around FFI bindings.
+fn is_ffi_block<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) -> bool {
+    let ops = unsafe_ops(cx, block);
+    !ops.is_empty()
+        && ops
+            .iter()
+            .all(|op| called_fn(cx, op).is_some_and(|(id, _)| cx.tcx.is_foreign_item(id)))
+}
+
+/// Points to the configuration for blocks of FFI calls, which often can't be narrowed in a
+/// meaningful way.
+fn help_ffi_config(diag: &mut Diag<'_, ()>) {
+    diag.help(
+        "if the foreign functions are meant to be called this way, list them in \
+        `minimal-unsafe-block-allowed-callees`, or their bindings module in `allowed-unsafe-block-modules`",
+    );
+}
+
+/// Points out that the `unsafe` blocks a diagnostic asks for will need their own `SAFETY` comments
+/// where `clippy::undocumented_unsafe_blocks` is enabled.
+fn note_safety_comments(cx: &LateContext<'_>, diag: &mut Diag<'_, ()>, block: &Block<'_>) {
+    if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, block.hir_id) {
+        diag.note(
+            "`clippy::undocumented_unsafe_blocks` is enabled, \
+            so each new `unsafe` block will need its own `SAFETY` comment",
+        );
+    }
+}
+
+/// Suggests extracting an unsafe function that is called repeatedly in the block into a safe
+/// wrapper function, so that its safety requirements are upheld and documented in one place
+/// instead of at every call.
+fn help_safe_wrapper<'tcx>(cx: &LateContext<'tcx>, diag: &mut Diag<'_, ()>, block: &'tcx Block<'tcx>) {
+    let mut calls: Vec<(DefId, bool, usize)> = Vec::new();
+    for op in unsafe_ops(cx, block) {
+        let Some((id, is_method)) = called_fn(cx, op) else {
+            continue;
+        };
+        match calls.iter_mut().find(|(other, ..)| *other == id) {
+            Some((.., count)) => *count += 1,
+            None => calls.push((id, is_method, 1)),
+        }
+    }
+    let Some(&(id, is_method, count)) = calls
+        .iter()
+        .filter(|(.., count)| *count >= REPEATED_CALLS)
+        .max_by_key(|(.., count)| *count)
+    else {
+        return;
+    };
+
+    let sig = cx.tcx.fn_sig(id).instantiate_identity();
+    let mut generics = Vec::new();
+    let mut owner = Some(id);
+    while let Some(def_id) = owner {
+        let own = cx.tcx.generics_of(def_id);
+        let params = own.own_params.iter().filter(|param| match param.kind {
+            GenericParamDefKind::Lifetime | GenericParamDefKind::Type { .. } => param.name != kw::SelfUpper,
+            GenericParamDefKind::Const { .. } => false,
+        });
+        generics.splice(0..0, params.map(|param| param.name.to_string()));
+        owner = own.parent;
+    }
+    generics.extend(sig.bound_vars().iter().filter_map(|var| match var {
+        ty::BoundVariableKind::Region(ty::BoundRegionKind::Named(_, name)) if name != kw::UnderscoreLifetime => {
+            Some(name.to_string())
+        },
+        _ => None,
+    }));
+    let sig = sig.skip_binder();
+    let args: Vec<_> = cx
+        .tcx
+        .fn_arg_names(id)
+        .iter()
+        .enumerate()
+        .map(|(i, ident)| match ident.name {
+            kw::SelfLower => "this".to_owned(),
+            kw::Empty | kw::Underscore => format!("arg{i}"),
+            name => name.to_string(),
+        })
+        .collect();
+    let params: Vec<_> = args
+        .iter()
+        .zip(sig.inputs())
+        .map(|(arg, ty)| format!("{arg}: {ty}"))
+        .collect();
+    let name = cx.tcx.item_name(id);
+    let call = match &args[..] {
+        [receiver, rest @ ..] if is_method => format!("{receiver}.{name}({})", rest.join(", ")),
+        _ => format!("{}({})", cx.tcx.def_path_str(id), args.join(", ")),
+    };
+    let generics = if generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.join(", "))
+    };
+    let output = if sig.output().is_unit() {
+        String::new()
+    } else {
+        format!(" -> {}", sig.output())
+    };
+    diag.help(format!(
+        "`{name}` is called {count} times, consider extracting it into a safe wrapper function that \
+        upholds its safety requirements in one place, e.g.:\n\
+        fn safe_{name}{generics}({}){output} {{\n    \
+            // SAFETY: <why the requirements of `{name}` hold for all arguments>\n    \
+            unsafe {{ {call} }}\n\
+        }}",
+        params.join(", "),
+    ));
+}
+
+/// Returns the number of statements of a block and its unsafe operations. Statements expanded
+/// from the same macro call count as one, and so does the tail expression.
+fn covered_ops<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    stmts: &[&'tcx Stmt<'tcx>],
+    stmt_spans: &[Span],
+) -> (usize, Vec<&'tcx Expr<'tcx>>) {
+    let ops = stmts
+        .iter()
+        .flat_map(|stmt| unsafe_ops(cx, *stmt))
+        .chain(block.expr.into_iter().flat_map(|tail| unsafe_ops(cx, tail)))
+        .collect();
+    let mut stmt_spans = stmt_spans.to_vec();
+    stmt_spans.dedup();
+    (stmt_spans.len() + usize::from(block.expr.is_some()), ops)
+}
+
+/// Summarizes the unsafe operations of a block if they are fewer than its statements, as in "the
+/// `unsafe` block covers 5 statements but only 1 unsafe operation", unless the message of the
+/// diagnostic has the counts already.
+fn ops_summary(reason: Reason, stmt_count: usize, op_count: usize) -> Option<String> {
+    if op_count >= stmt_count || matches!(reason, Reason::CoversStmts { .. }) {
+        return None;
+    }
+    Some(format!(
+        "the `unsafe` block covers {stmt_count} statements but only {op_count} unsafe operation{}",
+        if op_count == 1 { "" } else { "s" },
+    ))
+}
+
+/// Explains why the `unsafe` block can't just be removed, as in "the `unsafe` block is still
+/// needed: the call to `ptr::read` on line 12 requires an unsafe context".
+fn note_unsafe_context<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    block: &'tcx Block<'tcx>,
+    ops: &[&'tcx Expr<'tcx>],
+) {
+    let source_map = cx.sess().source_map();
+    let spans: Vec<_> = ops
+        .iter()
+        .map(|op| walk_span_to_context(op.span, block.span.ctxt()).unwrap_or(op.span))
+        .collect();
+    // Operations of the same kind on the same line, as in `f(unsafe_fn(0) + unsafe_fn(1))`, are
+    // only listed once.
+    let mut listed: Vec<(Span, String)> = Vec::new();
+    for (op, &span) in ops.iter().zip(&spans) {
+        let desc = describe_unsafe_op(cx, op);
+        if let Some((last_span, last_desc)) = listed.last()
+            && *last_desc == desc
+            && source_map.lookup_char_pos(last_span.lo()).line == source_map.lookup_char_pos(span.lo()).line
+        {
+            continue;
+        }
+        listed.push((span, desc));
+    }
+    match listed.len() {
+        0 => {},
+        1..=MAX_LISTED_OPS => {
+            let mut note_span = MultiSpan::from_spans(listed.iter().map(|&(span, _)| span).collect());
+            for (span, desc) in listed.iter().cloned() {
+                note_span.push_span_label(span, desc);
+            }
+            diag.span_note(
+                note_span,
+                if listed.len() == 1 {
+                    "the `unsafe` block is still needed: this operation requires an unsafe context"
+                } else {
+                    "the `unsafe` block is still needed: these operations require an unsafe context"
+                },
+            );
+        },
+        count => {
+            let lo = spans.iter().map(|span| span.lo()).min().unwrap_or(block.span.lo());
+            let hi = spans.iter().map(|span| span.hi()).max().unwrap_or(block.span.hi());
+            diag.span_note(
+                block.span.with_lo(lo).with_hi(hi),
+                format!("the `unsafe` block is still needed: its {count} unsafe operations require an unsafe context"),
+            );
+        },
+    }
+}
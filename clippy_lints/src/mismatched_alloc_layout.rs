@@ -0,0 +1,196 @@
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{expr_or_init, match_def_path, path_def_id, paths};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `dealloc` and `realloc`, from `std::alloc` or `GlobalAlloc`, with a
+    /// pointer allocated in the same function with a different `Layout`, i.e. a layout of a
+    /// different type, or of a different constant size or alignment.
+    ///
+    /// ### Why is this bad?
+    /// Memory must be deallocated with the same layout it was allocated with. Anything else is
+    /// undefined behavior, and usually corrupts the heap.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::alloc::{Layout, alloc, dealloc};
+    ///
+    /// unsafe {
+    ///     let ptr = alloc(Layout::new::<u64>());
+    ///     dealloc(ptr, Layout::new::<u32>());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// use std::alloc::{Layout, alloc, dealloc};
+    ///
+    /// unsafe {
+    ///     let ptr = alloc(Layout::new::<u64>());
+    ///     dealloc(ptr, Layout::new::<u64>());
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub MISMATCHED_ALLOC_LAYOUT,
+    suspicious,
+    "deallocating memory with a different layout than it was allocated with"
+}
+
+declare_lint_pass!(MismatchedAllocLayout => [MISMATCHED_ALLOC_LAYOUT]);
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedAllocLayout {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && let Some((fn_id, args)) = alloc_fn_call(cx, expr)
+            && let name = cx.tcx.item_name(fn_id)
+            && matches!(name.as_str(), "dealloc" | "realloc")
+            && let [ptr, layout, ..] = args
+            && let Some(alloc_layout) = allocation_layout(cx, ptr)
+            && let Some(desc) = LayoutDesc::from_expr(cx, layout)
+            && let Some(alloc_desc) = LayoutDesc::from_expr(cx, alloc_layout)
+        {
+            let note = match (alloc_desc.size_align(cx), desc.size_align(cx)) {
+                (Some(alloc_size_align), Some(size_align)) if alloc_size_align != size_align => format!(
+                    "the allocation has a size of {} and an alignment of {}, \
+                    but this layout has a size of {} and an alignment of {}",
+                    alloc_size_align.0, alloc_size_align.1, size_align.0, size_align.1
+                ),
+                (Some(_), Some(_)) => return,
+                _ => match (alloc_desc, desc) {
+                    (LayoutDesc::Type(alloc_ty), LayoutDesc::Type(ty)) if alloc_ty != ty => {
+                        format!("the allocation has the layout of `{alloc_ty}`, but this layout is of `{ty}`")
+                    },
+                    (LayoutDesc::Array(alloc_ty, _), LayoutDesc::Array(ty, _)) if alloc_ty != ty => {
+                        format!("the allocation is an array of `{alloc_ty}`, but this layout is of an array of `{ty}`")
+                    },
+                    _ => return,
+                },
+            };
+
+            span_lint_and_then(
+                cx,
+                MISMATCHED_ALLOC_LAYOUT,
+                expr.span,
+                format!("`{name}` with a different layout than the allocation"),
+                |diag| {
+                    diag.span_note(alloc_layout.span, "the memory is allocated with this layout");
+                    diag.note(note);
+                },
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum LayoutDesc<'tcx> {
+    /// `Layout::new::<T>()`
+    Type(Ty<'tcx>),
+    /// `Layout::array::<T>(n)`
+    Array(Ty<'tcx>, &'tcx Expr<'tcx>),
+    /// `Layout::from_size_align(size, align)`
+    SizeAlign(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>),
+}
+
+impl<'tcx> LayoutDesc<'tcx> {
+    fn from_expr(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        loop {
+            expr = expr_or_init(cx, expr);
+            match expr.kind {
+                ExprKind::MethodCall(path, recv, _, _) if matches!(path.ident.as_str(), "unwrap" | "expect") => {
+                    expr = recv;
+                },
+                _ => break,
+            }
+        }
+        if let ExprKind::Call(func, args) = expr.kind
+            && let Some(fn_id) = path_def_id(cx, func)
+            && let Some(impl_id) = cx.tcx.impl_of_method(fn_id)
+            && let ty::Adt(adt, _) = cx.tcx.type_of(impl_id).instantiate_identity().kind()
+            && cx.tcx.is_lang_item(adt.did(), LangItem::AllocLayout)
+        {
+            let generic_args = cx.typeck_results().node_args(func.hir_id);
+            match (cx.tcx.item_name(fn_id).as_str(), args) {
+                ("new", []) => Some(Self::Type(generic_args.type_at(0))),
+                ("array", [count]) => Some(Self::Array(generic_args.type_at(0), count)),
+                ("from_size_align" | "from_size_align_unchecked", [size, align]) => Some(Self::SizeAlign(size, align)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the size and the alignment of the layout, if they're known.
+    fn size_align(self, cx: &LateContext<'tcx>) -> Option<(u64, u64)> {
+        let eval_u64 = |expr| match ConstEvalCtxt::new(cx).eval(expr) {
+            Some(Constant::Int(value)) => u64::try_from(value).ok(),
+            _ => None,
+        };
+        let ty_size_align = |ty| {
+            cx.layout_of(ty)
+                .ok()
+                .map(|layout| (layout.size.bytes(), layout.align.abi.bytes()))
+        };
+        match self {
+            Self::Type(ty) => ty_size_align(ty),
+            Self::Array(ty, count) => {
+                let (size, align) = ty_size_align(ty)?;
+                Some((size.checked_mul(eval_u64(count)?)?, align))
+            },
+            Self::SizeAlign(size, align) => Some((eval_u64(size)?, eval_u64(align)?)),
+        }
+    }
+}
+
+/// Returns the function and its arguments, without the allocator, if the expression calls a
+/// function of `std::alloc` or a method of `GlobalAlloc`.
+fn alloc_fn_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(DefId, &'tcx [Expr<'tcx>])> {
+    let (fn_id, args) = match expr.kind {
+        ExprKind::Call(func, args) => (path_def_id(cx, func)?, args),
+        ExprKind::MethodCall(_, _, args, _) => (cx.typeck_results().type_dependent_def_id(expr.hir_id)?, args),
+        _ => return None,
+    };
+    if let Some(trait_id) = cx.tcx.trait_of_item(fn_id)
+        && match_def_path(cx, trait_id, &paths::GLOBAL_ALLOC)
+    {
+        // Skip the allocator in `GlobalAlloc::dealloc(&alloc, ptr, layout)`
+        let args = if matches!(expr.kind, ExprKind::Call(..)) {
+            args.get(1..)?
+        } else {
+            args
+        };
+        Some((fn_id, args))
+    } else if match_def_path(cx, cx.tcx.parent(fn_id), &paths::ALLOC_MODULE) {
+        Some((fn_id, args))
+    } else {
+        None
+    }
+}
+
+/// Returns the layout the pointer was allocated with, if it was allocated with `alloc` or
+/// `alloc_zeroed` in the same function.
+fn allocation_layout<'tcx>(cx: &LateContext<'tcx>, mut ptr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    loop {
+        ptr = expr_or_init(cx, ptr);
+        match ptr.kind {
+            ExprKind::Cast(inner, _) => ptr = inner,
+            ExprKind::MethodCall(path, recv, [], _)
+                if matches!(path.ident.as_str(), "cast" | "cast_mut" | "cast_const") =>
+            {
+                ptr = recv;
+            },
+            _ => break,
+        }
+    }
+    let (fn_id, args) = alloc_fn_call(cx, ptr)?;
+    match (cx.tcx.item_name(fn_id).as_str(), args) {
+        ("alloc" | "alloc_zeroed", [layout]) => Some(layout),
+        _ => None,
+    }
+}
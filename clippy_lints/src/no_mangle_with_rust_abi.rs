@@ -1,8 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::{snippet, snippet_with_applicability};
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind};
+use rustc_hir::{FnRetTy, FnSig, GenericParamKind, Generics, Item, ItemKind, TyKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::ty;
 use rustc_session::declare_lint_pass;
 use rustc_span::{BytePos, Pos};
 use rustc_target::spec::abi::Abi;
@@ -33,11 +35,52 @@ declare_clippy_lint! {
     pedantic,
     "convert Rust ABI functions to C ABI"
 }
-declare_lint_pass!(NoMangleWithRustAbi => [NO_MANGLE_WITH_RUST_ABI]);
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions with a foreign ABI, exported with `#[no_mangle]` or `#[export_name]`,
+    /// whose signature has generic type parameters, references, or types of the crate without a
+    /// `#[repr]` usable from other languages.
+    ///
+    /// ### Why is this bad?
+    /// The foreign caller can't instantiate generic parameters, and can't know the layout of
+    /// `repr(Rust)` types. It also can't uphold the guarantees of references: it may pass a null,
+    /// dangling or aliased pointer, which is undefined behavior for a reference.
+    ///
+    /// `improper_ctypes_definitions` accepts references, as they have the layout of pointers.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Config {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub extern "C" fn configure(config: &Config) {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[repr(C)]
+    /// struct Config {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// #[no_mangle]
+    /// pub unsafe extern "C" fn configure(config: *const Config) {}
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub NO_MANGLE_WITH_NON_FFI_SIGNATURE,
+    pedantic,
+    "exported functions with generic parameters, references or `repr(Rust)` types in their signature"
+}
+
+declare_lint_pass!(NoMangleWithRustAbi => [NO_MANGLE_WITH_RUST_ABI, NO_MANGLE_WITH_NON_FFI_SIGNATURE]);
 
 impl<'tcx> LateLintPass<'tcx> for NoMangleWithRustAbi {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
-        if let ItemKind::Fn(fn_sig, _, _) = &item.kind {
+        if let ItemKind::Fn(fn_sig, generics, _) = &item.kind {
+            check_signature(cx, item, fn_sig, generics);
+
             let attrs = cx.tcx.hir().attrs(item.hir_id());
             let mut app = Applicability::MaybeIncorrect;
             let fn_snippet = snippet_with_applicability(cx, fn_sig.span.with_hi(item.ident.span.lo()), "..", &mut app);
@@ -69,3 +112,65 @@ impl<'tcx> LateLintPass<'tcx> for NoMangleWithRustAbi {
         }
     }
 }
+
+fn check_signature<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, fn_sig: &FnSig<'tcx>, generics: &Generics<'_>) {
+    let def_id = item.owner_id.def_id;
+    let attrs = cx.tcx.codegen_fn_attrs(def_id);
+    if fn_sig.header.abi == Abi::Rust
+        || !(attrs.flags.contains(CodegenFnAttrFlags::NO_MANGLE) || attrs.export_name.is_some())
+        || item.span.from_expansion()
+    {
+        return;
+    }
+
+    let mut issues = Vec::new();
+    for param in generics.params {
+        if param.is_impl_trait() {
+            issues.push((param.span, "this `impl Trait` is a generic parameter".to_owned()));
+        } else if !matches!(param.kind, GenericParamKind::Lifetime { .. }) {
+            issues.push((param.span, format!("`{}` is a generic parameter", param.name.ident())));
+        }
+    }
+
+    let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+    let output = match fn_sig.decl.output {
+        FnRetTy::Return(hir_ty) => Some((hir_ty, sig.output())),
+        FnRetTy::DefaultReturn(_) => None,
+    };
+    let inputs = fn_sig.decl.inputs.iter().zip(sig.inputs().iter().copied());
+    for (i, (hir_ty, ty)) in inputs.chain(output).enumerate() {
+        if matches!(hir_ty.kind, TyKind::Ref(..)) {
+            let note = if i < sig.inputs().len() {
+                "this is a reference, which the foreign caller may pass as null, dangling or aliased"
+            } else {
+                "this is a reference, whose lifetime the foreign caller can't uphold"
+            };
+            issues.push((hir_ty.span, note.to_owned()));
+        } else if let TyKind::OpaqueDef(..) = hir_ty.kind {
+            issues.push((hir_ty.span, "this is an `impl Trait` type".to_owned()));
+        } else if let ty::Adt(adt, _) = ty.kind()
+            && adt.did().is_local()
+            && !(adt.repr().c() || adt.repr().transparent() || adt.repr().int.is_some())
+        {
+            issues.push((
+                hir_ty.span,
+                format!("`{ty}` has the `Rust` representation, whose layout is unspecified"),
+            ));
+        }
+    }
+
+    if !issues.is_empty() {
+        span_lint_and_then(
+            cx,
+            NO_MANGLE_WITH_NON_FFI_SIGNATURE,
+            fn_sig.span,
+            "exported function with a signature which can't be used from other languages",
+            |diag| {
+                for (span, note) in issues {
+                    diag.span_note(span, note);
+                }
+                diag.help("use concrete `#[repr(C)]` types, and raw pointers instead of references");
+            },
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{self, FnKind, Visitor};
+use rustc_hir::{AnonConst, Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, sym};
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `extern "C"` and `extern "system"` functions which can panic, through a panicking
+    /// macro such as `panic!`, `assert!` or `todo!`, or through `unwrap` or `expect`, outside of
+    /// `std::panic::catch_unwind`.
+    ///
+    /// ### Why is this bad?
+    /// A panic can't unwind out of these functions into the foreign caller. It aborts the process
+    /// instead, and was undefined behavior before Rust 1.81.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[no_mangle]
+    /// pub extern "C" fn parse(value: u32) -> u8 {
+    ///     u8::try_from(value).unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[no_mangle]
+    /// pub extern "C" fn parse(value: u32) -> u8 {
+    ///     u8::try_from(value).unwrap_or(u8::MAX)
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub PANIC_IN_EXTERN_C_FN,
+    pedantic,
+    "`extern \"C\"` functions which can panic"
+}
+
+declare_lint_pass!(PanicInExternCFn => [PANIC_IN_EXTERN_C_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInExternCFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        let (abi, sig_span) = match kind {
+            FnKind::ItemFn(ident, _, header) => (header.abi, span.with_hi(ident.span.hi())),
+            FnKind::Method(_, sig) => (sig.header.abi, sig.span),
+            FnKind::Closure => return,
+        };
+        if matches!(abi, Abi::C { unwind: false } | Abi::System { unwind: false })
+            && !in_external_macro(cx.tcx.sess, span)
+            && let Some(panic_span) = find_panic(cx, body.value)
+        {
+            let unwind_abi = if let Abi::C { .. } = abi {
+                "C-unwind"
+            } else {
+                "system-unwind"
+            };
+            span_lint_and_then(
+                cx,
+                PANIC_IN_EXTERN_C_FN,
+                sig_span,
+                format!("`extern \"{}\"` function which can panic", abi.name()),
+                |diag| {
+                    diag.span_note(
+                        panic_span,
+                        "a panic here can't unwind into the caller, and aborts the process",
+                    );
+                    diag.help("handle the error, or catch the panic with `std::panic::catch_unwind`");
+                    diag.help(format!(
+                        "otherwise, if the caller supports unwinding, use the `\"{unwind_abi}\"` ABI"
+                    ));
+                },
+            );
+        }
+    }
+}
+
+/// Returns the span of the first possible panic outside of `catch_unwind`.
+fn find_panic<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>) -> Option<Span> {
+    let mut visitor = FindPanic { cx, panic_span: None };
+    visitor.visit_expr(body);
+    visitor.panic_span
+}
+
+struct FindPanic<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    panic_span: Option<Span>,
+}
+
+impl<'tcx> Visitor<'tcx> for FindPanic<'_, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.panic_span.is_some() {
+            return;
+        }
+
+        if let Some(macro_call) = root_macro_call_first_node(self.cx, expr)
+            && (is_panic(self.cx, macro_call.def_id)
+                || matches!(
+                    self.cx.tcx.item_name(macro_call.def_id).as_str(),
+                    "assert" | "assert_eq" | "assert_ne" | "todo" | "unimplemented" | "unreachable"
+                ))
+        {
+            self.panic_span = Some(macro_call.span);
+            return;
+        }
+
+        match expr.kind {
+            // Panics inside of `catch_unwind` don't unwind any further
+            ExprKind::Call(func, _)
+                if path_def_id(self.cx, func).is_some_and(|id| match_def_path(self.cx, id, &paths::CATCH_UNWIND)) => {},
+            ExprKind::MethodCall(path, recv, _, _)
+                if matches!(path.ident.as_str(), "unwrap" | "expect")
+                    && let recv_ty = self.cx.typeck_results().expr_ty(recv).peel_refs()
+                    && (is_type_diagnostic_item(self.cx, recv_ty, sym::Option)
+                        || is_type_diagnostic_item(self.cx, recv_ty, sym::Result)) =>
+            {
+                self.panic_span = Some(expr.span);
+            },
+            _ => intravisit::walk_expr(self, expr),
+        }
+    }
+
+    // Panics in const blocks will cause compilation to fail.
+    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
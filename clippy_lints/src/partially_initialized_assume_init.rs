@@ -0,0 +1,385 @@
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_path_diagnostic_item, path_def_id, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, HirIdSet, LangItem, PatKind, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Symbol, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `MaybeUninit::uninit()` values of structs or arrays where only some of the
+    /// fields or elements are written, through `as_mut_ptr`, before `assume_init` is called.
+    ///
+    /// Only writes in the same block, before any other use of the value, are considered.
+    ///
+    /// ### Why is this bad?
+    /// `assume_init` requires the whole value to be initialized. Calling it while some fields are
+    /// still uninitialized is undefined behavior.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::mem::MaybeUninit;
+    /// # use std::ptr::addr_of_mut;
+    /// struct Point {
+    ///     x: u32,
+    ///     y: u32,
+    /// }
+    ///
+    /// let mut point = MaybeUninit::<Point>::uninit();
+    /// let ptr = point.as_mut_ptr();
+    /// let point = unsafe {
+    ///     addr_of_mut!((*ptr).x).write(1);
+    ///     point.assume_init()
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::mem::MaybeUninit;
+    /// # use std::ptr::addr_of_mut;
+    /// # struct Point {
+    /// #     x: u32,
+    /// #     y: u32,
+    /// # }
+    /// let mut point = MaybeUninit::<Point>::uninit();
+    /// let ptr = point.as_mut_ptr();
+    /// let point = unsafe {
+    ///     addr_of_mut!((*ptr).x).write(1);
+    ///     addr_of_mut!((*ptr).y).write(2);
+    ///     point.assume_init()
+    /// };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub PARTIALLY_INITIALIZED_ASSUME_INIT,
+    suspicious,
+    "`MaybeUninit::assume_init` after only some of the fields or elements are written"
+}
+
+declare_lint_pass!(PartiallyInitializedAssumeInit => [PARTIALLY_INITIALIZED_ASSUME_INIT]);
+
+impl<'tcx> LateLintPass<'tcx> for PartiallyInitializedAssumeInit {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let Some(init) = local.init
+                && !init.span.from_expansion()
+                && let ExprKind::Call(func, []) = init.kind
+                && is_path_diagnostic_item(cx, func, sym::maybe_uninit_uninit)
+                && let PatKind::Binding(_, local_id, _, None) = local.pat.kind
+                && let ty = cx.typeck_results().node_type(local.pat.hir_id)
+                && is_type_lang_item(cx, ty, LangItem::MaybeUninit)
+                && let ty::Adt(_, args) = ty.kind()
+                && let Some(parts) = Parts::new(cx, args.type_at(0))
+            {
+                let mut state = InitState {
+                    local_id,
+                    ptrs: HirIdSet::default(),
+                    elem_ptrs: HirIdSet::default(),
+                    initialized: parts.initially_initialized(cx),
+                    parts,
+                    written: false,
+                };
+                state.check_stmts(cx, &block.stmts[i + 1..], block.expr);
+            }
+        }
+    }
+}
+
+/// The parts of the value which have to be written separately.
+enum Parts<'tcx> {
+    Fields(Vec<(Symbol, Ty<'tcx>)>),
+    Elements(Ty<'tcx>, usize),
+}
+
+impl<'tcx> Parts<'tcx> {
+    fn new(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Self> {
+        match *ty.kind() {
+            ty::Adt(adt, args) if adt.is_struct() => Some(Self::Fields(
+                adt.non_enum_variant()
+                    .fields
+                    .iter()
+                    .map(|field| (field.name, field.ty(cx.tcx, args)))
+                    .collect(),
+            )),
+            ty::Array(elem_ty, len) => {
+                let len = usize::try_from(len.try_to_target_usize(cx.tcx)?).ok()?;
+                // Don't bother with huge arrays, which are never written element by element
+                (len <= 256).then_some(Self::Elements(elem_ty, len))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns which parts don't need to be written, because they are zero-sized or can be
+    /// uninitialized.
+    fn initially_initialized(&self, cx: &LateContext<'tcx>) -> Vec<bool> {
+        let is_initialized = |ty| {
+            is_type_lang_item(cx, ty, LangItem::MaybeUninit) || cx.layout_of(ty).is_ok_and(|layout| layout.is_zst())
+        };
+        match *self {
+            Self::Fields(ref fields) => fields.iter().map(|&(_, ty)| is_initialized(ty)).collect(),
+            Self::Elements(elem_ty, len) => vec![is_initialized(elem_ty); len],
+        }
+    }
+}
+
+enum Target {
+    Whole,
+    Part(usize),
+}
+
+struct InitState<'tcx> {
+    local_id: HirId,
+    /// Locals holding `as_mut_ptr()` of the value.
+    ptrs: HirIdSet,
+    /// Locals holding `as_mut_ptr()` of the value, cast to a pointer to the first element.
+    elem_ptrs: HirIdSet,
+    parts: Parts<'tcx>,
+    initialized: Vec<bool>,
+    written: bool,
+}
+
+impl<'tcx> InitState<'tcx> {
+    /// Returns `true` once the value is used in some other way than a write, which stops the
+    /// analysis.
+    fn check_stmts(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        stmts: &'tcx [Stmt<'tcx>],
+        expr: Option<&'tcx Expr<'tcx>>,
+    ) -> bool {
+        for stmt in stmts {
+            let stop = match stmt.kind {
+                StmtKind::Let(local) => {
+                    if let Some(init) = local.init
+                        && let PatKind::Binding(_, id, _, None) = local.pat.kind
+                        && local.els.is_none()
+                    {
+                        if self.is_ptr(cx, init) {
+                            self.ptrs.insert(id);
+                            false
+                        } else if self.is_elem_ptr(cx, init) {
+                            self.elem_ptrs.insert(id);
+                            false
+                        } else {
+                            self.check_expr(cx, init)
+                        }
+                    } else {
+                        local.init.is_some_and(|init| self.check_expr(cx, init))
+                    }
+                },
+                StmtKind::Expr(expr) | StmtKind::Semi(expr) => self.check_expr(cx, expr),
+                StmtKind::Item(_) => false,
+            };
+            if stop {
+                return true;
+            }
+        }
+        expr.is_some_and(|expr| self.check_expr(cx, expr))
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        match expr.kind {
+            ExprKind::Block(block, None) => self.check_stmts(cx, block.stmts, block.expr),
+            _ if self.check_write(cx, expr) => false,
+            _ => self.check_use(cx, expr),
+        }
+    }
+
+    /// Records the write if the expression writes to the value, or to a part of it.
+    fn check_write(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        let target = match expr.kind {
+            ExprKind::MethodCall(path, recv, [_], _)
+                if matches!(path.ident.as_str(), "write" | "write_unaligned" | "write_volatile") =>
+            {
+                if path_to_local_id(recv, self.local_id) {
+                    Some(Target::Whole)
+                } else {
+                    self.ptr_target(cx, recv)
+                }
+            },
+            ExprKind::Call(func, [dst, _])
+                if let Some(def_id) = path_def_id(cx, func)
+                    && matches!(
+                        cx.tcx.get_diagnostic_name(def_id),
+                        Some(sym::ptr_write | sym::ptr_write_unaligned | sym::ptr_write_volatile)
+                    ) =>
+            {
+                self.ptr_target(cx, dst)
+            },
+            ExprKind::Assign(place, _, _) => self.place_target(cx, place),
+            _ => None,
+        };
+        match target {
+            Some(Target::Whole) => self.initialized.fill(true),
+            Some(Target::Part(i)) => {
+                if let Some(initialized) = self.initialized.get_mut(i) {
+                    *initialized = true;
+                }
+            },
+            None => return false,
+        }
+        self.written = true;
+        true
+    }
+
+    /// Returns `true` if the expression uses the value or one of the pointers to it, and lints if
+    /// it's a call to `assume_init` while the value is only partially written.
+    fn check_use(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+        let Some(use_expr) = for_each_expr(cx, expr, |e| {
+            if let ExprKind::MethodCall(_, recv, [], _) = e.kind
+                && path_to_local_id(recv, self.local_id)
+                && is_maybe_uninit_method(cx, e, &["assume_init", "assume_init_read"])
+            {
+                ControlFlow::Break(Some(e))
+            } else if let Some(id) = path_to_local(e)
+                && (id == self.local_id || self.ptrs.contains(&id) || self.elem_ptrs.contains(&id))
+            {
+                ControlFlow::Break(None)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }) else {
+            return false;
+        };
+
+        if let Some(assume_init) = use_expr
+            && self.written
+            && let ExprKind::MethodCall(path, ..) = assume_init.kind
+        {
+            let uninit: Vec<_> = self
+                .initialized
+                .iter()
+                .enumerate()
+                .filter(|&(_, &initialized)| !initialized)
+                .map(|(i, _)| i)
+                .collect();
+            if uninit.is_empty() {
+                return true;
+            }
+
+            span_lint_and_then(
+                cx,
+                PARTIALLY_INITIALIZED_ASSUME_INIT,
+                assume_init.span,
+                format!("`{}` on a partially initialized value", path.ident),
+                |diag| {
+                    let (part, parts) = match self.parts {
+                        Parts::Fields(_) => ("field", "fields"),
+                        Parts::Elements(..) => ("element", "elements"),
+                    };
+                    let names: Vec<_> = uninit
+                        .iter()
+                        .take(4)
+                        .map(|&i| match self.parts {
+                            Parts::Fields(ref fields) => format!("`{}`", fields[i].0),
+                            Parts::Elements(..) => i.to_string(),
+                        })
+                        .collect();
+                    diag.note(match (uninit.len(), &*names) {
+                        (1, [name]) => format!("{part} {name} isn't written"),
+                        (2, [first, second]) => format!("{parts} {first} and {second} aren't written"),
+                        (len, names) => format!("{len} {parts} aren't written, including {}", names.join(", ")),
+                    });
+                    diag.help(format!("write the remaining {parts} before calling `{}`", path.ident));
+                },
+            );
+        }
+        true
+    }
+
+    /// Checks for `x.as_mut_ptr()` and its aliases.
+    fn is_ptr(&self, cx: &LateContext<'tcx>, expr: &Expr<'_>) -> bool {
+        if let Some(id) = path_to_local(expr) {
+            self.ptrs.contains(&id)
+        } else if let ExprKind::MethodCall(_, recv, [], _) = expr.kind {
+            path_to_local_id(recv, self.local_id) && is_maybe_uninit_method(cx, expr, &["as_mut_ptr"])
+        } else {
+            false
+        }
+    }
+
+    /// Checks for `x.as_mut_ptr()` cast to a pointer to the first element of an array, and its
+    /// aliases.
+    fn is_elem_ptr(&self, cx: &LateContext<'tcx>, expr: &Expr<'_>) -> bool {
+        let Parts::Elements(elem_ty, _) = self.parts else {
+            return false;
+        };
+        if let Some(id) = path_to_local(expr) {
+            return self.elem_ptrs.contains(&id);
+        }
+        let inner = match expr.kind {
+            ExprKind::Cast(inner, _) => inner,
+            ExprKind::MethodCall(path, recv, [], _) if path.ident.as_str() == "cast" => recv,
+            _ => return false,
+        };
+        matches!(*cx.typeck_results().expr_ty(expr).kind(), ty::RawPtr(ty, _) if ty == elem_ty)
+            && self.is_ptr(cx, inner)
+    }
+
+    /// Returns the part of the value a pointer points to.
+    fn ptr_target(&self, cx: &LateContext<'tcx>, ptr: &'tcx Expr<'tcx>) -> Option<Target> {
+        match ptr.kind {
+            ExprKind::AddrOf(BorrowKind::Raw, _, place) => self.place_target(cx, place),
+            ExprKind::MethodCall(path, recv, [offset], _)
+                if matches!(path.ident.as_str(), "add" | "offset") && self.is_elem_ptr(cx, recv) =>
+            {
+                Some(Target::Part(const_index(cx, offset)?))
+            },
+            _ if self.is_elem_ptr(cx, ptr) => Some(Target::Part(0)),
+            _ if self.is_ptr(cx, ptr) => Some(Target::Whole),
+            _ => None,
+        }
+    }
+
+    /// Returns the part of the value a place expression, like `(*ptr).field`, refers to.
+    fn place_target(&self, cx: &LateContext<'tcx>, place: &'tcx Expr<'tcx>) -> Option<Target> {
+        match place.kind {
+            ExprKind::Field(base, ident)
+                if let ExprKind::Unary(UnOp::Deref, ptr) = base.kind
+                    && self.is_ptr(cx, ptr)
+                    && let Parts::Fields(ref fields) = self.parts =>
+            {
+                fields
+                    .iter()
+                    .position(|&(name, _)| name == ident.name)
+                    .map(Target::Part)
+            },
+            ExprKind::Index(base, index, _)
+                if let ExprKind::Unary(UnOp::Deref, ptr) = base.kind
+                    && self.is_ptr(cx, ptr) =>
+            {
+                Some(Target::Part(const_index(cx, index)?))
+            },
+            ExprKind::Unary(UnOp::Deref, ptr) => self.ptr_target(cx, ptr),
+            _ => None,
+        }
+    }
+}
+
+fn is_maybe_uninit_method(cx: &LateContext<'_>, call: &Expr<'_>, names: &[&str]) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(call.hir_id)
+        .is_some_and(|method_id| {
+            names.contains(&cx.tcx.item_name(method_id).as_str())
+                && cx.tcx.impl_of_method(method_id).is_some_and(|impl_id| {
+                    is_type_lang_item(
+                        cx,
+                        cx.tcx.type_of(impl_id).instantiate_identity(),
+                        LangItem::MaybeUninit,
+                    )
+                })
+        })
+}
+
+fn const_index(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<usize> {
+    match ConstEvalCtxt::new(cx).eval(expr)? {
+        Constant::Int(value) => usize::try_from(value).ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{implements_trait, is_type_lang_item};
+use clippy_utils::{get_parent_expr_for_hir, path_def_id, path_to_local};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, LangItem, Mutability};
+use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::{BytePos, Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Pin::new_unchecked(&mut x)`, where `x` is a local which doesn't implement
+    /// `Unpin`, and which is moved or mutably borrowed later in the same function.
+    ///
+    /// ### Why is this bad?
+    /// `Pin::new_unchecked` requires the value to never be moved again, even after the `Pin` is
+    /// dropped. Moving it, or borrowing it mutably, which allows moving it with `mem::swap` and
+    /// such, breaks this guarantee, which is undefined behavior for types relying on it.
+    ///
+    /// `pin!` pins a value in place without `unsafe`, and makes it impossible to move it
+    /// afterwards. `Box::pin` does the same on the heap.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::future::Future;
+    /// # use std::pin::Pin;
+    /// # fn poll(future: Pin<&mut impl Future>) {}
+    /// # fn consume(future: impl Future) {}
+    /// let mut future = async {};
+    /// poll(unsafe { Pin::new_unchecked(&mut future) });
+    /// consume(future);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::future::Future;
+    /// # use std::pin::{Pin, pin};
+    /// # fn poll(future: Pin<&mut impl Future>) {}
+    /// let mut future = pin!(async {});
+    /// poll(future.as_mut());
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub PINNED_LOCAL_MOVED,
+    suspicious,
+    "a local pinned with `Pin::new_unchecked` which is moved later"
+}
+
+declare_lint_pass!(PinnedLocalMoved => [PINNED_LOCAL_MOVED]);
+
+impl<'tcx> LateLintPass<'tcx> for PinnedLocalMoved {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && is_pin_new_unchecked(cx, func)
+            && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, pinned) = arg.kind
+            && let Some(local_id) = path_to_local(pinned)
+            && let pinned_ty = cx.typeck_results().expr_ty(pinned)
+            && let Some(unpin_trait) = cx.tcx.lang_items().unpin_trait()
+            && !implements_trait(cx, pinned_ty, unpin_trait, &[])
+            && let Some(body_id) = cx.enclosing_body
+        {
+            let mut delegate = MoveDelegate {
+                cx,
+                local_id,
+                after: expr.span.hi(),
+                first_use: None,
+            };
+            let body = cx.tcx.hir().body(body_id);
+            ExprUseVisitor::for_clippy(cx, body_id.hir_id.owner.def_id, &mut delegate)
+                .consume_body(body)
+                .into_ok();
+
+            if let Some((use_span, kind)) = delegate.first_use {
+                let name = cx.tcx.hir().name(local_id);
+                span_lint_and_then(
+                    cx,
+                    PINNED_LOCAL_MOVED,
+                    expr.span,
+                    format!(
+                        "`Pin::new_unchecked` on `{name}`, which is {} later",
+                        kind.description()
+                    ),
+                    |diag| {
+                        diag.span_note(use_span, format!("`{name}` is {} here", kind.description()));
+                        diag.note("a pinned value must never be moved again, even after the `Pin` is dropped");
+                        diag.help(format!(
+                            "use `std::pin::pin!` to pin `{name}` in place, or `Box::pin` to pin it on the heap"
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_pin_new_unchecked(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    path_def_id(cx, func).is_some_and(|fn_id| {
+        cx.tcx.item_name(fn_id) == sym::new_unchecked
+            && cx.tcx.impl_of_method(fn_id).is_some_and(|impl_id| {
+                is_type_lang_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), LangItem::Pin)
+            })
+    })
+}
+
+#[derive(Clone, Copy)]
+enum UseKind {
+    Moved,
+    MutablyBorrowed,
+}
+
+impl UseKind {
+    fn description(self) -> &'static str {
+        match self {
+            Self::Moved => "moved",
+            Self::MutablyBorrowed => "mutably borrowed",
+        }
+    }
+}
+
+/// Finds the first move or mutable borrow of the local after the given position.
+struct MoveDelegate<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    after: BytePos,
+    first_use: Option<(Span, UseKind)>,
+}
+
+impl<'tcx> MoveDelegate<'_, 'tcx> {
+    fn record(&mut self, place: &PlaceWithHirId<'tcx>, diag_expr_id: HirId, kind: UseKind) {
+        if place.place.base == PlaceBase::Local(self.local_id)
+            && let span = self.cx.tcx.hir().span(diag_expr_id)
+            && span.lo() >= self.after
+            && self.first_use.is_none_or(|(first_span, _)| span.lo() < first_span.lo())
+        {
+            self.first_use = Some((span, kind));
+        }
+    }
+}
+
+impl<'tcx> Delegate<'tcx> for MoveDelegate<'_, 'tcx> {
+    fn consume(&mut self, place: &PlaceWithHirId<'tcx>, diag_expr_id: HirId) {
+        self.record(place, diag_expr_id, UseKind::Moved);
+    }
+
+    fn borrow(&mut self, place: &PlaceWithHirId<'tcx>, diag_expr_id: HirId, bk: ty::BorrowKind) {
+        // Pinning the local again doesn't move it
+        let is_repinned = get_parent_expr_for_hir(self.cx, diag_expr_id)
+            .filter(|borrow| matches!(borrow.kind, ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, _)))
+            .and_then(|borrow| get_parent_expr_for_hir(self.cx, borrow.hir_id))
+            .is_some_and(|call| matches!(call.kind, ExprKind::Call(func, [_]) if is_pin_new_unchecked(self.cx, func)));
+        if bk != ty::BorrowKind::Immutable && !is_repinned {
+            self.record(place, diag_expr_id, UseKind::MutablyBorrowed);
+        }
+    }
+
+    fn mutate(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
@@ -0,0 +1,129 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, path_def_id, path_to_local};
+use core::ops::ControlFlow;
+use rustc_hir::{ConstContext, Expr, ExprKind, HirId, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `const fn`s which reinterpret pointers or references as integers, by transmuting
+    /// them or by reading an integer field of a local union which was written through a pointer
+    /// field in the same function.
+    ///
+    /// Transmutes of a raw pointer to an integer are left to rustc's
+    /// `ptr_to_integer_transmute_in_consts` lint.
+    ///
+    /// ### Why is this bad?
+    /// Pointers don't have an integer address during const evaluation, so these operations fail
+    /// whenever the function is called in a const context. The function compiles, and works at
+    /// runtime, so the error only shows up in the crates calling it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// const fn addr<T>(value: &T) -> usize {
+    ///     unsafe { std::mem::transmute(value) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn addr<T>(value: &T) -> usize {
+    ///     std::ptr::from_ref(value).addr()
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub PTR_TO_INT_IN_CONST_FN,
+    suspicious,
+    "reinterpreting pointers as integers in a `const fn`"
+}
+
+declare_lint_pass!(PtrToIntInConstFn => [PTR_TO_INT_IN_CONST_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for PtrToIntInConstFn {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let msg = match expr.kind {
+            ExprKind::Call(func, [arg])
+                if path_def_id(cx, func).is_some_and(|id| cx.tcx.is_diagnostic_item(sym::transmute, id))
+                    && let from_ty = cx.typeck_results().expr_ty(arg)
+                    && let to_ty = cx.typeck_results().expr_ty(expr)
+                    && is_ptr_like(from_ty)
+                    && is_int_like(to_ty)
+                    // Covered by rustc's `ptr_to_integer_transmute_in_consts`
+                    && !(from_ty.is_unsafe_ptr() && to_ty.is_integral()) =>
+            {
+                "transmute from a pointer to an integer in a `const fn`"
+            },
+            ExprKind::Field(base, _)
+                if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(base).kind()
+                    && adt.is_union()
+                    && is_int_like(cx.typeck_results().expr_ty(expr))
+                    && let Some(local_id) = path_to_local(base)
+                    && !get_parent_expr(cx, expr).is_some_and(
+                        |parent| matches!(parent.kind, ExprKind::Assign(lhs, ..) if lhs.hir_id == expr.hir_id),
+                    )
+                    && written_through_ptr_field(cx, local_id) =>
+            {
+                "reading an integer from a union holding a pointer in a `const fn`"
+            },
+            _ => return,
+        };
+        let hir = cx.tcx.hir();
+        if hir.body_const_context(hir.enclosing_body_owner(expr.hir_id)) == Some(ConstContext::ConstFn) {
+            span_lint_and_then(cx, PTR_TO_INT_IN_CONST_FN, expr.span, msg, |diag| {
+                diag.note("pointers have no integer address during const evaluation, so this fails when the function is called in a const context");
+                diag.help("if the address is only needed at runtime, consider making the function non-`const`");
+            });
+        }
+    }
+}
+
+/// Checks whether the union in the local is initialized or assigned through a pointer field in the
+/// enclosing body.
+fn written_through_ptr_field(cx: &LateContext<'_>, local_id: HirId) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    let typeck = cx.typeck_results();
+    for_each_expr(cx, cx.tcx.hir().body(body_id).value, |e| {
+        let written = match e.kind {
+            ExprKind::Struct(_, fields, _) => {
+                matches!(cx.tcx.parent_hir_node(e.hir_id), Node::LetStmt(local) if local.pat.hir_id == local_id)
+                    && fields.iter().any(|field| is_ptr_like(typeck.expr_ty(field.expr)))
+            },
+            ExprKind::Assign(lhs, ..) => {
+                matches!(lhs.kind, ExprKind::Field(base, _) if path_to_local(base) == Some(local_id))
+                    && is_ptr_like(typeck.expr_ty(lhs))
+            },
+            _ => false,
+        };
+        if written {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+fn is_ptr_like(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::RawPtr(..) | ty::Ref(..) | ty::FnPtr(..) => true,
+        ty::Array(ty, _) => is_ptr_like(*ty),
+        ty::Tuple(tys) => tys.iter().any(is_ptr_like),
+        _ => false,
+    }
+}
+
+fn is_int_like(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Int(_) | ty::Uint(_) => true,
+        ty::Array(ty, _) => is_int_like(*ty),
+        _ => false,
+    }
+}
@@ -0,0 +1,133 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{is_trait_impl_item, matches_any_path};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{Visitor, walk_ty};
+use rustc_hir::{FnRetTy, FnSig, ImplItem, ImplItemKind, Item, ItemKind, TraitItem, TraitItemKind, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported functions and trait methods with raw pointers in their signatures.
+    /// Functions with a foreign ABI aren't checked, nor are the modules listed in the
+    /// `allowed-raw-pointer-modules` configuration, nor the raw pointer conversions named
+    /// `as_ptr`, `as_mut_ptr`, `into_raw` and `from_raw`.
+    ///
+    /// ### Why is this bad?
+    /// A raw pointer doesn't say whether it may be null, dangling, or aliased, or how many elements
+    /// it points to. References, `NonNull` and slices encode these invariants in the type, so that
+    /// the compiler checks them instead of the documentation.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn sum(values: *const u32, len: usize) -> u32 {
+    ///     (0..len).map(|i| unsafe { *values.add(i) }).sum()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn sum(values: &[u32]) -> u32 {
+    ///     values.iter().sum()
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub RAW_POINTER_IN_PUBLIC_API,
+    pedantic,
+    "exported functions with raw pointers in their signatures"
+}
+
+impl_lint_pass!(RawPointerInPublicApi => [RAW_POINTER_IN_PUBLIC_API]);
+
+pub struct RawPointerInPublicApi {
+    allowed_modules: Vec<Vec<&'static str>>,
+}
+
+/// Methods whose whole point is to convert from or into a raw pointer.
+const RAW_POINTER_CONVERSIONS: &[&str] = &["as_ptr", "as_mut_ptr", "into_raw", "from_raw"];
+
+impl RawPointerInPublicApi {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allowed_modules: conf
+                .allowed_raw_pointer_modules
+                .iter()
+                .map(|path| path.split("::").collect())
+                .collect(),
+        }
+    }
+
+    fn check_sig<'tcx>(&self, cx: &LateContext<'tcx>, def_id: LocalDefId, sig: &FnSig<'tcx>) {
+        if sig.header.abi != Abi::Rust
+            || RAW_POINTER_CONVERSIONS.contains(&cx.tcx.item_name(def_id.to_def_id()).as_str())
+            || in_external_macro(cx.tcx.sess, sig.span)
+            || !cx.effective_visibilities.is_exported(def_id)
+            || matches_any_path(
+                cx,
+                cx.tcx.parent_module_from_def_id(def_id).to_def_id(),
+                &self.allowed_modules,
+            )
+        {
+            return;
+        }
+
+        let mut visitor = FindRawPtrs(Vec::new());
+        for ty in sig.decl.inputs {
+            visitor.visit_ty(ty);
+        }
+        if let FnRetTy::Return(ty) = sig.decl.output {
+            visitor.visit_ty(ty);
+        }
+        if !visitor.0.is_empty() {
+            span_lint_and_help(
+                cx,
+                RAW_POINTER_IN_PUBLIC_API,
+                visitor.0,
+                "raw pointer in the signature of an exported function",
+                None,
+                "consider using a reference, `NonNull` or a slice, which encode the pointer's invariants in its type",
+            );
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for RawPointerInPublicApi {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(sig, ..) = &item.kind {
+            self.check_sig(cx, item.owner_id.def_id, sig);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Trait implementations have to follow the signatures of the trait
+        if let ImplItemKind::Fn(sig, _) = &item.kind
+            && !is_trait_impl_item(cx, item.hir_id())
+        {
+            self.check_sig(cx, item.owner_id.def_id, sig);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tcx>) {
+        if let TraitItemKind::Fn(sig, _) = &item.kind {
+            self.check_sig(cx, item.owner_id.def_id, sig);
+        }
+    }
+}
+
+/// Collects the spans of the raw pointer types written in a type.
+struct FindRawPtrs(Vec<Span>);
+
+impl<'tcx> Visitor<'tcx> for FindRawPtrs {
+    fn visit_ty(&mut self, ty: &'tcx Ty<'tcx>) {
+        if let TyKind::Ptr(_) = ty.kind
+            && !ty.span.from_expansion()
+        {
+            self.0.push(ty.span);
+        } else {
+            walk_ty(self, ty);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_with_context, unsafe_keyword_span};
+use clippy_utils::span_contains_comment;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::{Applicability, Diag};
+use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, Node, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe` blocks nested in another `unsafe` block.
+    ///
+    /// ### Why is this bad?
+    /// The inner block is redundant, as its code is already in an unsafe context. Since
+    /// `unused_unsafe` doesn't warn about nested `unsafe` blocks, they tend to be left over when
+    /// code is moved around.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let ptr = &1 as *const i32;
+    /// unsafe {
+    ///     let x = ptr.read();
+    ///     let y = unsafe { ptr.add(0).read() };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let ptr = &1 as *const i32;
+    /// unsafe {
+    ///     let x = ptr.read();
+    ///     let y = ptr.add(0).read();
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub REDUNDANT_NESTED_UNSAFE_BLOCK,
+    style,
+    "`unsafe` block nested in another `unsafe` block"
+}
+
+declare_lint_pass!(RedundantNestedUnsafeBlock => [REDUNDANT_NESTED_UNSAFE_BLOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantNestedUnsafeBlock {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Block(block, _) = expr.kind
+            && is_user_unsafe_block(block)
+            && !expr.span.from_expansion()
+            && let Some(outer) = enclosing_unsafe_block(cx, expr)
+            && outer.span.eq_ctxt(expr.span)
+            && !in_external_macro(cx.sess(), outer.span)
+        {
+            span_lint_and_then(
+                cx,
+                REDUNDANT_NESTED_UNSAFE_BLOCK,
+                expr.span,
+                "`unsafe` block nested in another `unsafe` block",
+                |diag| {
+                    diag.span_label(unsafe_keyword_span(cx, outer), "already in this `unsafe` block");
+                    suggest_removal(cx, diag, expr, block, outer);
+                },
+            );
+        }
+    }
+}
+
+fn is_user_unsafe_block(block: &Block<'_>) -> bool {
+    block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+}
+
+/// Returns the innermost `unsafe` block around the expression in the same body, or in a body of a
+/// closure around it.
+fn enclosing_unsafe_block<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<&'tcx Block<'tcx>> {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Block(block) if is_user_unsafe_block(block) => return Some(block),
+            Node::Item(_)
+            | Node::ImplItem(_)
+            | Node::TraitItem(_)
+            | Node::ForeignItem(_)
+            | Node::AnonConst(_)
+            | Node::ConstBlock(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Suggests removing the outer block if it only wraps the inner one, or else the inner one.
+fn suggest_removal<'tcx>(
+    cx: &LateContext<'tcx>,
+    diag: &mut Diag<'_, ()>,
+    expr: &'tcx Expr<'tcx>,
+    block: &'tcx Block<'tcx>,
+    outer: &'tcx Block<'tcx>,
+) {
+    let removes_comment = |spans: &[Span]| {
+        spans
+            .iter()
+            .any(|span| span_contains_comment(cx.sess().source_map(), *span))
+    };
+
+    if outer.stmts.is_empty() && outer.expr.is_some_and(|tail| tail.hir_id == expr.hir_id) {
+        let mut app = if removes_comment(&[
+            outer.span.until(expr.span),
+            expr.span.between(outer.span.shrink_to_hi()),
+        ]) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let (snip, _) = snippet_with_context(cx, expr.span, outer.span.ctxt(), "..", &mut app);
+        let snip = reindent_multiline(snip, true, indent_of(cx, outer.span));
+        diag.span_suggestion(outer.span, "remove the outer `unsafe` block", snip, app);
+    } else if block.stmts.is_empty()
+        && let Some(tail) = block.expr
+    {
+        let mut app = if removes_comment(&[
+            block.span.until(tail.span),
+            tail.span.between(block.span.shrink_to_hi()),
+        ]) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let mut sugg = Sugg::hir_with_context(cx, tail, expr.span.ctxt(), "..", &mut app);
+        if needs_parens(cx, expr) {
+            sugg = sugg.maybe_par();
+        }
+        diag.span_suggestion(expr.span, "remove the inner `unsafe` block", sugg, app);
+    } else {
+        diag.span_suggestion(
+            unsafe_keyword_span(cx, block),
+            "remove the `unsafe` keyword of the inner block",
+            "",
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Checks whether the expression needs parentheses once its block is removed, as in
+/// `unsafe { a + b } * 2`.
+fn needs_parens(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        Node::Expr(parent) => match parent.kind {
+            ExprKind::Unary(..)
+            | ExprKind::Binary(..)
+            | ExprKind::Cast(..)
+            | ExprKind::Field(..)
+            | ExprKind::AddrOf(..) => true,
+            ExprKind::MethodCall(_, receiver, ..) | ExprKind::Index(receiver, ..) | ExprKind::Call(receiver, _) => {
+                receiver.hir_id == expr.hir_id
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,129 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::implements_trait_with_env;
+use rustc_ast::ImplPolarity;
+use rustc_hir::{Item, ItemKind, LangItem, Safety, TraitRef};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe impl Send` and `unsafe impl Sync` on generic types, where a type
+    /// parameter stored in the type isn't bounded by the implemented trait.
+    ///
+    /// ### Why is this bad?
+    /// Without a bound, the implementation also applies when the type parameter is e.g. `Rc<_>`
+    /// or `Cell<_>`, which allows sending or sharing them across threads, and leads to data races.
+    ///
+    /// ### Known problems
+    /// Type parameters are considered stored whenever they appear in the type of a field, except
+    /// in function pointers. Types which never give access to a value of the type parameter
+    /// should use `PhantomData<fn() -> T>` to express this.
+    ///
+    /// Types which only give access to one thread at a time, like `Mutex`, can soundly implement
+    /// `Sync` with only a `T: Send` bound, and should allow the lint.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Wrapper<T>(*mut T);
+    ///
+    /// unsafe impl<T> Send for Wrapper<T> {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Wrapper<T>(*mut T);
+    ///
+    /// unsafe impl<T: Send> Send for Wrapper<T> {}
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub SEND_SYNC_IMPL_WITHOUT_BOUNDS,
+    suspicious,
+    "`unsafe impl Send` or `Sync` for a generic type without bounds on its type parameters"
+}
+
+declare_lint_pass!(SendSyncImplWithoutBounds => [SEND_SYNC_IMPL_WITHOUT_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for SendSyncImplWithoutBounds {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Impl(hir_impl) = item.kind
+            && hir_impl.safety == Safety::Unsafe
+            && hir_impl.polarity == ImplPolarity::Positive
+            && !in_external_macro(cx.sess(), item.span)
+            && let Some(trait_id) = hir_impl.of_trait.as_ref().and_then(TraitRef::trait_def_id)
+            && let Some(trait_name) = cx.tcx.get_diagnostic_name(trait_id)
+            && matches!(trait_name, sym::Send | sym::Sync)
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(item.owner_id)
+            && let self_ty = trait_ref.instantiate_identity().self_ty()
+            && let ty::Adt(adt, args) = *self_ty.kind()
+        {
+            let typing_env = ty::TypingEnv::non_body_analysis(cx.tcx, item.owner_id);
+
+            // The first field storing each unbounded type parameter.
+            let mut unbounded: Vec<(Ty<'tcx>, &ty::FieldDef)> = Vec::new();
+            for field in adt.all_fields() {
+                for param in stored_params(field.ty(cx.tcx, args)) {
+                    if !unbounded.iter().any(|&(p, _)| p == param)
+                        && !implements_trait_with_env(cx.tcx, typing_env, param, trait_id, None, &[])
+                    {
+                        unbounded.push((param, field));
+                    }
+                }
+            }
+            if unbounded.is_empty() {
+                return;
+            }
+
+            let bounds = unbounded
+                .iter()
+                .map(|(param, _)| format!("`{param}: {trait_name}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let msg = if let [(param, _)] = unbounded[..] {
+                format!("`unsafe impl {trait_name}` for `{self_ty}` without a `{param}: {trait_name}` bound")
+            } else {
+                format!("`unsafe impl {trait_name}` for `{self_ty}` without {bounds} bounds")
+            };
+            span_lint_and_then(cx, SEND_SYNC_IMPL_WITHOUT_BOUNDS, item.span, msg, |diag| {
+                for &(param, field) in &unbounded {
+                    diag.span_note(
+                        cx.tcx.def_span(field.did),
+                        format!("`{param}` is stored in field `{}`", field.name),
+                    );
+                }
+                if unbounded.len() == 1 {
+                    diag.help(format!("consider adding a {bounds} bound"));
+                } else {
+                    diag.help(format!("consider adding {bounds} bounds"));
+                }
+                for &(param, field) in &unbounded {
+                    if let ty::Adt(field_adt, _) = cx.tcx.type_of(field.did).instantiate_identity().kind()
+                        && cx.tcx.is_lang_item(field_adt.did(), LangItem::PhantomData)
+                    {
+                        diag.help(format!(
+                            "if `{}` never owns or shares a `{param}`, consider using `PhantomData<fn() -> {param}>`",
+                            cx.tcx.item_name(adt.did())
+                        ));
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Returns the type parameters appearing in the type of a field, except in function pointers.
+fn stored_params(ty: Ty<'_>) -> Vec<Ty<'_>> {
+    let mut params = Vec::new();
+    let mut walker = ty.walk();
+    while let Some(arg) = walker.next() {
+        if let GenericArgKind::Type(ty) = arg.unpack() {
+            match ty.kind() {
+                ty::FnPtr(..) => walker.skip_current_subtree(),
+                ty::Param(_) => params.push(ty),
+                _ => {},
+            }
+        }
+    }
+    params
+}
@@ -1,6 +1,10 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{expr_or_init, path_def_id};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::{self, Ty};
 use rustc_session::declare_lint_pass;
 use rustc_span::sym;
@@ -30,7 +34,36 @@ declare_clippy_lint! {
     "using `size_of::<T>` or `size_of_val::<T>` where a count of elements of `T` is expected"
 }
 
-declare_lint_pass!(SizeOfInElementCount => [SIZE_OF_IN_ELEMENT_COUNT]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `slice::from_raw_parts` and similar functions where the length is
+    /// measured in a different unit than the elements of the slice: it's multiplied by the size
+    /// of another type, or it's the `len()` of a buffer with elements of a different size.
+    ///
+    /// ### Why is this bad?
+    /// The length is a count of elements of the slice. A count of bytes, or of elements of
+    /// another type, makes the slice longer or shorter than the memory it points to. A longer slice
+    /// is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let bytes = vec![0u8; 16];
+    /// let words = unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u32, bytes.len()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let bytes = vec![0u8; 16];
+    /// let words = unsafe {
+    ///     std::slice::from_raw_parts(bytes.as_ptr() as *const u32, bytes.len() / std::mem::size_of::<u32>())
+    /// };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub MISMATCHED_FROM_RAW_PARTS_LEN,
+    suspicious,
+    "`slice::from_raw_parts` with a length measured in a different unit than the elements of the slice"
+}
+
+declare_lint_pass!(SizeOfInElementCount => [SIZE_OF_IN_ELEMENT_COUNT, MISMATCHED_FROM_RAW_PARTS_LEN]);
 
 fn get_size_of_ty<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, inverted: bool) -> Option<Ty<'tcx>> {
     match expr.kind {
@@ -130,6 +163,125 @@ impl<'tcx> LateLintPass<'tcx> for SizeOfInElementCount {
             && pointee_ty == ty_used_for_size_of
         {
             span_lint_and_help(cx, SIZE_OF_IN_ELEMENT_COUNT, count_expr.span, LINT_MSG, None, HELP_MSG);
-        };
+        } else if let ExprKind::Call(func, [_, len]) = expr.kind
+            && let Some(def_id) = path_def_id(cx, func)
+            && matches!(
+                cx.tcx.get_diagnostic_name(def_id),
+                Some(
+                    sym::ptr_slice_from_raw_parts
+                        | sym::ptr_slice_from_raw_parts_mut
+                        | sym::slice_from_raw_parts
+                        | sym::slice_from_raw_parts_mut
+                )
+            )
+            && let Some(elem_ty) = cx.typeck_results().node_args(func.hir_id).types().next()
+        {
+            check_from_raw_parts_len(cx, elem_ty, len);
+        }
+    }
+}
+
+fn check_from_raw_parts_len<'tcx>(cx: &LateContext<'tcx>, elem_ty: Ty<'tcx>, len: &'tcx Expr<'tcx>) {
+    let size = |ty| cx.layout_of(ty).ok().map(|layout| layout.size.bytes());
+    let elem_size = size(elem_ty);
+    let len_snip = snippet(cx, len.span, "..");
+
+    let mut sizes = SizeOfFactors::default();
+    sizes.collect(cx, len, false);
+    if sizes.divisors.is_empty()
+        && let Some(&size_of_ty) = sizes.factors.first()
+        // The same type is linted by `size_of_in_element_count`, and a count of bytes is right for
+        // a slice of bytes
+        && !sizes.factors.contains(&elem_ty)
+        && elem_size != Some(1)
+    {
+        span_lint_and_then(
+            cx,
+            MISMATCHED_FROM_RAW_PARTS_LEN,
+            len.span,
+            format!("the length is multiplied by the size of `{size_of_ty}`, but the elements are `{elem_ty}`"),
+            |diag| {
+                diag.note("the length is a count of elements, not of bytes");
+                diag.help(format!(
+                    "if `{len_snip}` is a count of bytes, divide it by the size of the elements: \
+                    `{len_snip} / std::mem::size_of::<{elem_ty}>()`"
+                ));
+            },
+        );
+    } else if let ExprKind::MethodCall(path, recv, [], _) = expr_or_init(cx, len).kind
+        && path.ident.name == sym::len
+        && let Some(buf_elem_ty) = buffer_elem_ty(cx, cx.typeck_results().expr_ty(recv).peel_refs())
+        && buf_elem_ty != elem_ty
+        && let buf_elem_size = size(buf_elem_ty)
+        && match (buf_elem_size, elem_size) {
+            (Some(buf_elem_size), Some(elem_size)) => buf_elem_size != elem_size,
+            _ => true,
+        }
+    {
+        span_lint_and_then(
+            cx,
+            MISMATCHED_FROM_RAW_PARTS_LEN,
+            len.span,
+            format!("the length is a count of `{buf_elem_ty}`, but the elements are `{elem_ty}`"),
+            |diag| {
+                let bytes = if buf_elem_size == Some(1) {
+                    len_snip.into_owned()
+                } else {
+                    format!("{len_snip} * std::mem::size_of::<{buf_elem_ty}>()")
+                };
+                diag.help(format!(
+                    "convert the length to a count of `{elem_ty}`: `{bytes} / std::mem::size_of::<{elem_ty}>()`"
+                ));
+            },
+        );
+    }
+}
+
+/// The types of the `size_of` calls an expression is multiplied and divided by.
+#[derive(Default)]
+struct SizeOfFactors<'tcx> {
+    factors: Vec<Ty<'tcx>>,
+    divisors: Vec<Ty<'tcx>>,
+}
+
+impl<'tcx> SizeOfFactors<'tcx> {
+    fn collect(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, inverted: bool) {
+        match expr.kind {
+            ExprKind::Call(func, _)
+                if let Some(def_id) = path_def_id(cx, func)
+                    && matches!(
+                        cx.tcx.get_diagnostic_name(def_id),
+                        Some(sym::mem_size_of | sym::mem_size_of_val)
+                    )
+                    && let Some(ty) = cx.typeck_results().node_args(func.hir_id).types().next() =>
+            {
+                if inverted {
+                    self.divisors.push(ty);
+                } else {
+                    self.factors.push(ty);
+                }
+            },
+            ExprKind::Binary(op, left, right) if op.node == BinOpKind::Mul => {
+                self.collect(cx, left, inverted);
+                self.collect(cx, right, inverted);
+            },
+            ExprKind::Binary(op, left, right) if op.node == BinOpKind::Div => {
+                self.collect(cx, left, inverted);
+                self.collect(cx, right, !inverted);
+            },
+            ExprKind::Cast(expr, _) => self.collect(cx, expr, inverted),
+            _ => {},
+        }
+    }
+}
+
+/// Returns the element type of a slice, an array, a `Vec` or a string.
+fn buffer_elem_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        ty::Slice(elem_ty) | ty::Array(elem_ty, _) => Some(*elem_ty),
+        ty::Str => Some(cx.tcx.types.u8),
+        ty::Adt(_, args) if is_type_diagnostic_item(cx, ty, sym::Vec) => Some(args.type_at(0)),
+        ty::Adt(..) if is_type_diagnostic_item(cx, ty, sym::String) => Some(cx.tcx.types.u8),
+        _ => None,
     }
 }
@@ -0,0 +1,160 @@
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_then};
+use clippy_utils::is_no_std_crate;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Diag;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, ForeignItem, ForeignItemKind, Item, ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, IntTy, Ty, UintTy};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `static mut` items, including the ones declared in `extern` blocks, and for
+    /// uses of `static mut` items from other crates.
+    ///
+    /// ### Why restrict this?
+    /// Every access to a `static mut` is unsafe, and it's up to the programmer to make sure that
+    /// no two threads access it at the same time, and that no reference to it outlives the next
+    /// write. References to a `static mut` are denied by default in edition 2024.
+    ///
+    /// Depending on the type of the item, an atomic type, `OnceLock`, `Mutex`, or `SyncUnsafeCell`
+    /// can usually be used instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// static mut COUNTER: usize = 0;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::atomic::AtomicUsize;
+    /// static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub STATIC_MUT,
+    restriction,
+    "usage of `static mut`"
+}
+
+declare_lint_pass!(StaticMut => [STATIC_MUT]);
+
+impl<'tcx> LateLintPass<'tcx> for StaticMut {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Static(_, Mutability::Mut, _) = item.kind
+            && !in_external_macro(cx.sess(), item.span)
+        {
+            let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
+            span_lint_and_then(
+                cx,
+                STATIC_MUT,
+                cx.tcx.def_span(item.owner_id),
+                "usage of `static mut`",
+                |diag| {
+                    with_forced_trimmed_paths!(suggest_replacement(cx, diag, item.ident, ty));
+                },
+            );
+        }
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ForeignItem<'tcx>) {
+        if let ForeignItemKind::Static(_, Mutability::Mut, _) = item.kind
+            && !in_external_macro(cx.sess(), item.span)
+        {
+            let ty = cx.tcx.type_of(item.owner_id).instantiate_identity();
+            span_lint_and_then(
+                cx,
+                STATIC_MUT,
+                cx.tcx.def_span(item.owner_id),
+                "usage of `static mut`",
+                |diag| {
+                    // The type has to match the foreign definition, so only atomics are suggested.
+                    with_forced_trimmed_paths!(if let Some(atomic) = atomic_type(ty) {
+                        diag.help(format!(
+                            "consider declaring it with an atomic type: `static {}: {atomic}`",
+                            item.ident
+                        ));
+                    } else {
+                        diag.help(format!(
+                            "if it's only read, consider declaring it without `mut`: `static {}: {ty}`",
+                            item.ident
+                        ));
+                    });
+                },
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // Items of the current crate are linted at their definition.
+        if let ExprKind::Path(ref qpath) = expr.kind
+            && let Res::Def(
+                DefKind::Static {
+                    mutability: Mutability::Mut,
+                    ..
+                },
+                def_id,
+            ) = cx.qpath_res(qpath, expr.hir_id)
+            && !def_id.is_local()
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            span_lint_and_note(
+                cx,
+                STATIC_MUT,
+                expr.span,
+                "usage of `static mut`",
+                None,
+                format!(
+                    "`{}` is a `static mut` of the `{}` crate",
+                    cx.tcx.item_name(def_id),
+                    cx.tcx.crate_name(def_id.krate)
+                ),
+            );
+        }
+    }
+}
+
+/// Suggests a type to replace the `static mut` with, depending on its type `ty`.
+fn suggest_replacement(cx: &LateContext<'_>, diag: &mut Diag<'_, ()>, name: Ident, ty: Ty<'_>) {
+    if let Some(atomic) = atomic_type(ty) {
+        diag.help(format!("consider using an atomic type: `static {name}: {atomic}`"));
+    } else if is_no_std_crate(cx) {
+        diag.help(format!(
+            "if the accesses are synchronized otherwise, consider using \
+            `static {name}: SyncUnsafeCell<{ty}>` once it's stable"
+        ));
+    } else if let ty::Adt(_, args) = ty.kind()
+        && is_type_diagnostic_item(cx, ty, sym::Option)
+    {
+        diag.help(format!(
+            "if it's only initialized once, consider using `static {name}: OnceLock<{}>`",
+            args.type_at(0)
+        ));
+        diag.help(format!("otherwise, consider using `static {name}: Mutex<{ty}>`"));
+    } else {
+        diag.help(format!("consider using `static {name}: Mutex<{ty}>`"));
+    }
+}
+
+/// Returns the atomic type which can replace the type of the item, if any.
+fn atomic_type(ty: Ty<'_>) -> Option<String> {
+    let atomic = match *ty.kind() {
+        ty::Bool => "AtomicBool",
+        ty::Int(IntTy::I8) => "AtomicI8",
+        ty::Int(IntTy::I16) => "AtomicI16",
+        ty::Int(IntTy::I32) => "AtomicI32",
+        ty::Int(IntTy::I64) => "AtomicI64",
+        ty::Int(IntTy::Isize) => "AtomicIsize",
+        ty::Uint(UintTy::U8) => "AtomicU8",
+        ty::Uint(UintTy::U16) => "AtomicU16",
+        ty::Uint(UintTy::U32) => "AtomicU32",
+        ty::Uint(UintTy::U64) => "AtomicU64",
+        ty::Uint(UintTy::Usize) => "AtomicUsize",
+        ty::RawPtr(pointee, _) => return Some(format!("AtomicPtr<{pointee}>")),
+        _ => return None,
+    };
+    Some(atomic.to_owned())
+}
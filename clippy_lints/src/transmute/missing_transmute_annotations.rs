@@ -7,7 +7,7 @@ use rustc_middle::ty::Ty;
 
 use crate::transmute::MISSING_TRANSMUTE_ANNOTATIONS;
 
-fn get_parent_local_binding_ty<'tcx>(cx: &LateContext<'tcx>, expr_hir_id: HirId) -> Option<LetStmt<'tcx>> {
+pub(super) fn get_parent_local_binding_ty<'tcx>(cx: &LateContext<'tcx>, expr_hir_id: HirId) -> Option<LetStmt<'tcx>> {
     let mut parent_iter = cx.tcx.hir().parent_iter(expr_hir_id);
     if let Some((_, node)) = parent_iter.next() {
         match node {
@@ -28,7 +28,7 @@ fn get_parent_local_binding_ty<'tcx>(cx: &LateContext<'tcx>, expr_hir_id: HirId)
     }
 }
 
-fn is_function_block(cx: &LateContext<'_>, expr_hir_id: HirId) -> bool {
+pub(super) fn is_function_block(cx: &LateContext<'_>, expr_hir_id: HirId) -> bool {
     let def_id = cx.tcx.hir().enclosing_body_owner(expr_hir_id);
     if let Some(body) = cx.tcx.hir().maybe_body_owned_by(def_id) {
         return body.value.peel_blocks().hir_id == expr_hir_id;
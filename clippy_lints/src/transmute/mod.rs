@@ -6,12 +6,14 @@ mod transmute_int_to_bool;
 mod transmute_int_to_char;
 mod transmute_int_to_float;
 mod transmute_int_to_non_zero;
+mod transmute_lifetime_extension;
 mod transmute_null_to_fn;
 mod transmute_num_to_bytes;
 mod transmute_ptr_to_ptr;
 mod transmute_ptr_to_ref;
 mod transmute_ref_to_ref;
 mod transmute_undefined_repr;
+mod transmute_with_safe_alternative;
 mod transmutes_expressible_as_ptr_casts;
 mod transmuting_null;
 mod unsound_collection_transmute;
@@ -24,6 +26,7 @@ use clippy_utils::is_in_const_context;
 use clippy_utils::msrvs::Msrv;
 use rustc_hir::{Expr, ExprKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TyCtxt;
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::sym;
 
@@ -573,8 +576,71 @@ declare_clippy_lint! {
     "warns if a transmute call doesn't have all generics specified"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between types which only differ in their lifetimes, such as
+    /// `transmute::<&'a T, &'static T>`. Lifetimes which aren't known, such as elided ones, are
+    /// assumed not to be `'static`.
+    ///
+    /// The types are taken from the type parameters of `transmute`, the annotated type of the
+    /// binding or the parameter the value comes from or goes to, and the return type of the
+    /// function.
+    ///
+    /// ### Why is this bad?
+    /// The only effect of such a transmute is to extend a lifetime, which the borrow checker
+    /// rejected for a reason: the reference can outlive the value it points to, leading to a
+    /// use after free.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn name(names: &[String]) -> &'static str {
+    ///     unsafe { std::mem::transmute::<&str, &'static str>(names[0].as_str()) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn name(names: &[String]) -> &str {
+    ///     names[0].as_str()
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub TRANSMUTE_LIFETIME_EXTENSION,
+    suspicious,
+    "transmutes which only change lifetimes"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between types for which a safe conversion function is listed in the
+    /// `transmute-conversions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Crates often provide safe conversions, which check the invariants of their types, e.g. with
+    /// the help of `zerocopy` or `bytemuck`. A transmute bypasses these checks.
+    ///
+    /// ### Example
+    /// ```toml
+    /// transmute-conversions = [
+    ///     { from = "[u8; 4]", to = "my_crate::Header", replacement = "my_crate::Header::from_bytes" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// let header: Header = unsafe { std::mem::transmute(bytes) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let header = Header::from_bytes(bytes);
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub TRANSMUTE_WITH_SAFE_ALTERNATIVE,
+    style,
+    "transmutes for which a safe conversion function is configured"
+}
+
 pub struct Transmute {
     msrv: Msrv,
+    safe_alternatives: Vec<transmute_with_safe_alternative::SafeAlternative>,
 }
 impl_lint_pass!(Transmute => [
     CROSSPOINTER_TRANSMUTE,
@@ -596,11 +662,14 @@ impl_lint_pass!(Transmute => [
     TRANSMUTE_NULL_TO_FN,
     EAGER_TRANSMUTE,
     MISSING_TRANSMUTE_ANNOTATIONS,
+    TRANSMUTE_LIFETIME_EXTENSION,
+    TRANSMUTE_WITH_SAFE_ALTERNATIVE,
 ]);
 impl Transmute {
-    pub fn new(conf: &'static Conf) -> Self {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
         Self {
             msrv: conf.msrv.clone(),
+            safe_alternatives: transmute_with_safe_alternative::resolve(tcx, &conf.transmute_conversions),
         }
     }
 }
@@ -630,6 +699,8 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
             }
 
             let linted = wrong_transmute::check(cx, e, from_ty, to_ty)
+                | transmute_with_safe_alternative::check(cx, e, from_ty, to_ty, &self.safe_alternatives)
+                | transmute_lifetime_extension::check(cx, e, from_ty, to_ty, path_expr, arg)
                 | crosspointer_transmute::check(cx, e, from_ty, to_ty)
                 | transmuting_null::check(cx, e, arg, to_ty)
                 | transmute_null_to_fn::check(cx, e, arg, to_ty)
@@ -0,0 +1,184 @@
+use super::TRANSMUTE_LIFETIME_EXTENSION;
+use super::missing_transmute_annotations::{get_parent_local_binding_ty, is_function_block};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Expr, HirId, Node};
+use rustc_lint::LateContext;
+use rustc_middle::ty::fold::fold_regions;
+use rustc_middle::ty::{self, GenericArgKind, Region, Ty, TypeVisitableExt, UserTypeKind};
+
+/// Checks for `transmute_lifetime_extension` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+///
+/// The types of the typeck results have their lifetimes erased, so the lifetimes are taken from
+/// the types written by the user, or from the signature of the function.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    from_ty: Ty<'tcx>,
+    to_ty: Ty<'tcx>,
+    path_expr: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+) -> bool {
+    if from_ty != to_ty || !from_ty.has_erased_regions() {
+        return false;
+    }
+
+    let (from_written, to_written) = turbofish_tys(cx, path_expr);
+    let from_ty = written_ty(cx, from_ty, [from_written, source_ty(cx, arg)]);
+    let to_ty = written_ty(cx, to_ty, [to_written, destination_ty(cx, e.hir_id)]);
+
+    let from_regions = regions(from_ty);
+    let to_regions = regions(to_ty);
+    if from_regions.len() != to_regions.len()
+        || !from_regions
+            .iter()
+            .zip(&to_regions)
+            .any(|(&from, &to)| extends(from, to))
+    {
+        return false;
+    }
+
+    span_lint_and_then(
+        cx,
+        TRANSMUTE_LIFETIME_EXTENSION,
+        e.span,
+        format!(
+            "transmute from `{}` to `{}`, which only changes lifetimes",
+            erase_unknown_regions(cx, from_ty),
+            erase_unknown_regions(cx, to_ty),
+        ),
+        |diag| {
+            diag.note("the result may outlive the value it borrows from");
+            if to_regions.iter().any(|r| r.is_static()) && !from_regions.iter().any(|r| r.is_static()) {
+                diag.help("if the value should live for the rest of the program, consider using `Box::leak`");
+                diag.help("otherwise, consider restructuring the code so that the value lives long enough");
+            } else {
+                diag.help("consider restructuring the code so that the value lives long enough");
+            }
+        },
+    );
+    true
+}
+
+/// Checks whether transmuting from the first region to the second one extends it. Regions which
+/// aren't known, such as elided or inferred ones, are assumed not to be `'static`.
+fn extends<'tcx>(from: Region<'tcx>, to: Region<'tcx>) -> bool {
+    if to.is_static() {
+        !from.is_static()
+    } else {
+        is_named(from) && is_named(to) && from != to
+    }
+}
+
+fn is_named(region: Region<'_>) -> bool {
+    matches!(region.kind(), ty::ReEarlyParam(_) | ty::ReLateParam(_))
+}
+
+fn is_known(region: Region<'_>) -> bool {
+    region.is_static() || is_named(region)
+}
+
+/// Returns the regions of the type, in the order they're written.
+fn regions(ty: Ty<'_>) -> Vec<Region<'_>> {
+    ty.walk()
+        .filter_map(|arg| match arg.unpack() {
+            GenericArgKind::Lifetime(region) => Some(region),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the first of the written types with all of its lifetimes known, or with some unknown
+/// lifetimes if there's none, falling back to the type of the typeck results.
+fn written_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, written: [Option<Ty<'tcx>>; 2]) -> Ty<'tcx> {
+    let mut written = written
+        .into_iter()
+        .flatten()
+        .filter(|&written| erase_all_regions(cx, written) == ty);
+    let first = written.next();
+    first
+        .into_iter()
+        .chain(written)
+        .find(|&written| regions(written).into_iter().all(is_known))
+        .or(first)
+        .unwrap_or(ty)
+}
+
+fn erase_all_regions<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Ty<'tcx> {
+    fold_regions(cx.tcx, ty, |_, _| cx.tcx.lifetimes.re_erased)
+}
+
+fn erase_unknown_regions<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Ty<'tcx> {
+    fold_regions(cx.tcx, ty, |region, _| {
+        if is_known(region) {
+            region
+        } else {
+            cx.tcx.lifetimes.re_erased
+        }
+    })
+}
+
+/// Returns the types given as type parameters of `transmute`.
+fn turbofish_tys<'tcx>(cx: &LateContext<'tcx>, path_expr: &Expr<'_>) -> (Option<Ty<'tcx>>, Option<Ty<'tcx>>) {
+    if let Some(user_ty) = cx.typeck_results().user_provided_types().get(path_expr.hir_id)
+        && let UserTypeKind::TypeOf(_, user_args) = user_ty.value.kind
+    {
+        let mut types = user_args.args.types();
+        (types.next(), types.next())
+    } else {
+        (None, None)
+    }
+}
+
+/// Returns the type annotation of a `let` statement.
+fn annotated_ty<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<Ty<'tcx>> {
+    match cx.typeck_results().user_provided_types().get(hir_id)?.value.kind {
+        UserTypeKind::Ty(ty) => Some(ty),
+        UserTypeKind::TypeOf(..) => None,
+    }
+}
+
+/// Returns the signature of the function owning the body, with its late-bound lifetimes
+/// liberated.
+fn fn_sig<'tcx>(cx: &LateContext<'tcx>, owner: LocalDefId) -> Option<ty::FnSig<'tcx>> {
+    matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn).then(|| {
+        cx.tcx
+            .liberate_late_bound_regions(owner.to_def_id(), cx.tcx.fn_sig(owner).instantiate_identity())
+    })
+}
+
+/// Returns the declared type of the transmuted value, if it's a parameter of the function or a
+/// local with a type annotation.
+fn source_ty<'tcx>(cx: &LateContext<'tcx>, arg: &Expr<'_>) -> Option<Ty<'tcx>> {
+    let local_id = path_to_local(arg)?;
+    match cx.tcx.parent_hir_node(local_id) {
+        Node::LetStmt(local) if local.pat.hir_id == local_id => annotated_ty(cx, local.ty?.hir_id),
+        Node::Param(param) => {
+            let owner = cx.tcx.hir().enclosing_body_owner(param.hir_id);
+            let index = cx
+                .tcx
+                .hir()
+                .body_owned_by(owner)
+                .params
+                .iter()
+                .position(|p| p.hir_id == param.hir_id)?;
+            fn_sig(cx, owner)?.inputs().get(index).copied()
+        },
+        _ => None,
+    }
+}
+
+/// Returns the annotated type of the binding the transmuted value is assigned to, or the return
+/// type of the function if it's returned.
+fn destination_ty<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<Ty<'tcx>> {
+    if let Some(local) = get_parent_local_binding_ty(cx, hir_id) {
+        annotated_ty(cx, local.ty?.hir_id)
+    } else if is_function_block(cx, hir_id) {
+        Some(fn_sig(cx, cx.tcx.hir().enclosing_body_owner(hir_id))?.output())
+    } else {
+        None
+    }
+}
@@ -1,6 +1,7 @@
 use super::TRANSMUTE_UNDEFINED_REPR;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::ty::is_c_void;
+use rustc_errors::Diag;
 use rustc_hir::Expr;
 use rustc_lint::LateContext;
 use rustc_middle::ty::{self, GenericArgsRef, IntTy, Ty, UintTy};
@@ -128,6 +129,13 @@ pub(super) fn check<'tcx>(
                                 diag.note(format!("the contained type `{to_ty}` has an undefined layout"));
                             }
                         }
+                        let helped_repr =
+                            help_repr(cx, diag, from_ty) | (same_adt_did.is_none() && help_repr(cx, diag, to_ty));
+                        if helped_repr {
+                            diag.help("otherwise, consider converting between the types without `transmute`");
+                        } else {
+                            diag.help("consider converting between the types without `transmute`");
+                        }
                     },
                 );
                 return true;
@@ -145,6 +153,7 @@ pub(super) fn check<'tcx>(
                         if from_ty_orig.peel_refs() != from_ty {
                             diag.note(format!("the contained type `{from_ty}` has an undefined layout"));
                         }
+                        help_repr(cx, diag, from_ty);
                     },
                 );
                 return true;
@@ -162,6 +171,7 @@ pub(super) fn check<'tcx>(
                         if to_ty_orig.peel_refs() != to_ty {
                             diag.note(format!("the contained type `{to_ty}` has an undefined layout"));
                         }
+                        help_repr(cx, diag, to_ty);
                     },
                 );
                 return true;
@@ -179,6 +189,26 @@ pub(super) fn check<'tcx>(
     false
 }
 
+/// Suggests giving a defined layout to the type if it's a struct of the current crate.
+/// Returns `true` if the help was added.
+fn help_repr(cx: &LateContext<'_>, diag: &mut Diag<'_, ()>, ty: Ty<'_>) -> bool {
+    if let ty::Adt(def, _) = ty.kind()
+        && def.is_struct()
+        && def.did().is_local()
+    {
+        diag.span_help(
+            cx.tcx.def_span(def.did()),
+            format!(
+                "consider adding `#[repr(C)]` to `{}` to define its layout",
+                cx.tcx.item_name(def.did())
+            ),
+        );
+        true
+    } else {
+        false
+    }
+}
+
 #[expect(clippy::struct_excessive_bools)]
 struct ReducedTys<'tcx> {
     from_ty: Ty<'tcx>,
@@ -0,0 +1,98 @@
+use super::TRANSMUTE_WITH_SAFE_ALTERNATIVE;
+use clippy_config::types::TransmuteConversion;
+use clippy_utils::def_path_def_ids;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::Expr;
+use rustc_hir::def_id::DefId;
+use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{Ty, TyCtxt};
+
+/// A configured conversion, with its paths resolved.
+pub(super) struct SafeAlternative {
+    from: TypeMatcher,
+    to: TypeMatcher,
+    /// The definitions of the replacement, which may transmute themselves.
+    replacement_ids: Vec<DefId>,
+    conversion: &'static TransmuteConversion,
+}
+
+enum TypeMatcher {
+    /// An ADT, with any generic arguments.
+    Adt(Vec<DefId>),
+    /// Any other type, as written in the configuration without whitespace.
+    Written(String),
+}
+
+impl TypeMatcher {
+    fn new(tcx: TyCtxt<'_>, ty: &str) -> Self {
+        let ids: Vec<_> = def_path_def_ids(tcx, &ty.split("::").collect::<Vec<_>>()).collect();
+        if ids.is_empty() {
+            Self::Written(ty.split_whitespace().collect())
+        } else {
+            Self::Adt(ids)
+        }
+    }
+
+    fn matches(&self, ty: Ty<'_>) -> bool {
+        match self {
+            Self::Adt(ids) => ty.ty_adt_def().is_some_and(|adt| ids.contains(&adt.did())),
+            Self::Written(written) => {
+                with_no_trimmed_paths!(ty.to_string())
+                    .split_whitespace()
+                    .collect::<String>()
+                    == *written
+            },
+        }
+    }
+}
+
+pub(super) fn resolve(tcx: TyCtxt<'_>, conversions: &'static [TransmuteConversion]) -> Vec<SafeAlternative> {
+    conversions
+        .iter()
+        .map(|conversion| SafeAlternative {
+            from: TypeMatcher::new(tcx, &conversion.from),
+            to: TypeMatcher::new(tcx, &conversion.to),
+            replacement_ids: def_path_def_ids(tcx, &conversion.replacement.split("::").collect::<Vec<_>>()).collect(),
+            conversion,
+        })
+        .collect()
+}
+
+/// Checks for `transmute_with_safe_alternative` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    from_ty: Ty<'tcx>,
+    to_ty: Ty<'tcx>,
+    safe_alternatives: &[SafeAlternative],
+) -> bool {
+    let Some(alternative) = safe_alternatives
+        .iter()
+        .find(|alternative| alternative.from.matches(from_ty) && alternative.to.matches(to_ty))
+    else {
+        return false;
+    };
+    if alternative
+        .replacement_ids
+        .contains(&cx.tcx.hir().get_parent_item(e.hir_id).to_def_id())
+    {
+        return false;
+    }
+
+    let conversion = alternative.conversion;
+    span_lint_and_then(
+        cx,
+        TRANSMUTE_WITH_SAFE_ALTERNATIVE,
+        e.span,
+        format!("transmute from `{from_ty}` to `{to_ty}`"),
+        |diag| {
+            if let Some(reason) = &conversion.reason {
+                diag.note(reason.clone());
+            }
+            diag.help(format!("use `{}` instead", conversion.replacement));
+        },
+    );
+    true
+}
@@ -0,0 +1,265 @@
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note, span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet;
+use clippy_utils::{is_in_const_context, match_def_path, path_def_id, paths, peel_ref_operators};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BlockCheckMode, Expr, ExprKind, Node, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, Symbol, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `NonZero::new_unchecked` with a constant zero, and to
+    /// `char::from_u32_unchecked` with a constant which isn't a valid `char`, such as a surrogate.
+    ///
+    /// ### Why is this bad?
+    /// The call is always undefined behavior.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::num::NonZeroU32;
+    /// let zero = unsafe { NonZeroU32::new_unchecked(0) };
+    /// let surrogate = unsafe { char::from_u32_unchecked(0xD800) };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub INVALID_UNCHECKED_CONSTRUCTOR,
+    correctness,
+    "`NonZero::new_unchecked` or `char::from_u32_unchecked` with an invalid constant"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `NonZero::new_unchecked` and `char::from_u32_unchecked` with a constant
+    /// which is known to be valid.
+    ///
+    /// ### Why is this bad?
+    /// The validity of a constant doesn't need to be assumed. A `char` literal, or the checked
+    /// constructor, is just as fast and doesn't need an `unsafe` block.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::num::NonZeroU32;
+    /// let one = unsafe { NonZeroU32::new_unchecked(1) };
+    /// let a = unsafe { char::from_u32_unchecked(0x61) };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::num::NonZeroU32;
+    /// let one = NonZeroU32::new(1).unwrap();
+    /// let a = 'a';
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNNECESSARY_UNCHECKED_CONSTRUCTOR,
+    pedantic,
+    "`NonZero::new_unchecked` or `char::from_u32_unchecked` with a valid constant"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `str::from_utf8_unchecked` with a byte string literal, or with a
+    /// constant byte array, which is valid UTF-8.
+    ///
+    /// ### Why is this bad?
+    /// A string literal doesn't need an `unsafe` block, and `str::from_utf8` can check a constant
+    /// at compile time, as it's `const`. Both keep working if the bytes are changed to invalid
+    /// UTF-8, which is undefined behavior with `from_utf8_unchecked`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let hello = unsafe { std::str::from_utf8_unchecked(b"hello") };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let hello = "hello";
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNNECESSARY_FROM_UTF8_UNCHECKED,
+    complexity,
+    "`str::from_utf8_unchecked` with constant bytes"
+}
+
+declare_lint_pass!(UncheckedConstructors => [
+    INVALID_UNCHECKED_CONSTRUCTOR,
+    UNNECESSARY_UNCHECKED_CONSTRUCTOR,
+    UNNECESSARY_FROM_UTF8_UNCHECKED,
+]);
+
+#[derive(Clone, Copy)]
+enum Constructor {
+    NonZero,
+    Char,
+}
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedConstructors {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(fn_id) = path_def_id(cx, func)
+            && cx.tcx.is_diagnostic_item(sym::str_from_utf8_unchecked, fn_id)
+        {
+            check_from_utf8_unchecked(cx, expr, arg);
+        } else if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(fn_id) = path_def_id(cx, func)
+            && let Some(constructor) = unchecked_constructor(cx, fn_id)
+            && let Some(Constant::Int(value)) = ConstEvalCtxt::new(cx).eval(arg)
+        {
+            let name = cx.tcx.item_name(fn_id);
+            match constructor {
+                Constructor::NonZero if value == 0 => {
+                    span_lint_and_note(
+                        cx,
+                        INVALID_UNCHECKED_CONSTRUCTOR,
+                        expr.span,
+                        format!("`{name}` with zero"),
+                        None,
+                        "this is undefined behavior",
+                    );
+                },
+                Constructor::NonZero => {
+                    // `Option::unwrap` isn't `const` on all supported versions
+                    if !is_in_const_context(cx)
+                        && let Some(path) = snippet(cx, func.span, "..").strip_suffix("new_unchecked")
+                    {
+                        let sugg = format!("{path}new({}).unwrap()", snippet(cx, arg.span, ".."));
+                        lint_valid_constant(cx, expr, name, sugg);
+                    }
+                },
+                Constructor::Char => match u32::try_from(value).ok().and_then(char::from_u32) {
+                    Some(c) => lint_valid_constant(cx, expr, name, format!("{c:?}")),
+                    None => span_lint_and_note(
+                        cx,
+                        INVALID_UNCHECKED_CONSTRUCTOR,
+                        expr.span,
+                        format!("`{name}` with `{value:#X}`, which isn't a valid `char`"),
+                        None,
+                        "this is undefined behavior",
+                    ),
+                },
+            }
+        }
+    }
+}
+
+fn check_from_utf8_unchecked(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>) {
+    let bytes = peel_ref_operators(cx, arg);
+    if let ExprKind::Lit(lit) = bytes.kind
+        && let LitKind::ByteStr(ref bytes, _) = lit.node
+        && let Ok(s) = std::str::from_utf8(bytes)
+    {
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_FROM_UTF8_UNCHECKED,
+            expr.span,
+            "`from_utf8_unchecked` with a byte string literal which is valid UTF-8",
+            |diag| {
+                let lit = snippet(cx, lit.span, "..");
+                // Escaped bytes may not be valid in a string literal
+                let sugg = match lit.strip_prefix('b') {
+                    Some(lit) if !lit.contains('\\') => lit.to_owned(),
+                    _ => format!("{s:?}"),
+                };
+                diag.span_suggestion(
+                    unsafe_block_span(cx, expr),
+                    "use a string literal instead",
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    } else if let Some(def_id) = path_def_id(cx, bytes)
+        && let Some(def_id) = def_id.as_local()
+        && cx.tcx.def_kind(def_id) == DefKind::Const
+        && let Some(body) = cx.tcx.hir().maybe_body_owned_by(def_id)
+        && let Some(bytes) = ConstEvalCtxt::with_env(
+            cx.tcx,
+            ty::TypingEnv::post_analysis(cx.tcx, def_id),
+            cx.tcx.typeck(def_id),
+        )
+        .eval(body.value)
+        .and_then(constant_bytes)
+        && std::str::from_utf8(&bytes).is_ok()
+    {
+        span_lint_and_help(
+            cx,
+            UNNECESSARY_FROM_UTF8_UNCHECKED,
+            expr.span,
+            "`from_utf8_unchecked` with a constant which is valid UTF-8",
+            None,
+            format!(
+                "consider checking `{}` at compile time instead, in a `&str` constant initialized \
+                with `std::str::from_utf8`, which is `const`",
+                cx.tcx.item_name(def_id.to_def_id())
+            ),
+        );
+    }
+}
+
+/// Returns the bytes of a constant byte string or byte array.
+fn constant_bytes(constant: Constant<'_>) -> Option<Vec<u8>> {
+    match constant.peel_refs() {
+        Constant::Binary(bytes) => Some(bytes.to_vec()),
+        Constant::Vec(elements) => elements
+            .into_iter()
+            .map(|element| match element {
+                Constant::Int(byte) => u8::try_from(byte).ok(),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn lint_valid_constant(cx: &LateContext<'_>, expr: &Expr<'_>, name: Symbol, sugg: String) {
+    span_lint_and_sugg(
+        cx,
+        UNNECESSARY_UNCHECKED_CONSTRUCTOR,
+        unsafe_block_span(cx, expr),
+        format!("`{name}` with a valid constant"),
+        "use the checked value instead",
+        sugg,
+        Applicability::MachineApplicable,
+    );
+}
+
+/// Returns the span of the `unsafe` block around the call if it only contains the call, and the
+/// span of the call otherwise.
+fn unsafe_block_span(cx: &LateContext<'_>, expr: &Expr<'_>) -> Span {
+    if let Node::Block(block) = cx.tcx.parent_hir_node(expr.hir_id)
+        && block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+        && block.stmts.is_empty()
+        && let Node::Expr(block_expr) = cx.tcx.parent_hir_node(block.hir_id)
+        && !block_expr.span.from_expansion()
+    {
+        block_expr.span
+    } else {
+        expr.span
+    }
+}
+
+fn unchecked_constructor(cx: &LateContext<'_>, fn_id: DefId) -> Option<Constructor> {
+    let self_ty = cx
+        .tcx
+        .impl_of_method(fn_id)
+        .map(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity());
+    match cx.tcx.item_name(fn_id).as_str() {
+        "new_unchecked"
+            if self_ty
+                .and_then(Ty::ty_adt_def)
+                .is_some_and(|adt| cx.tcx.is_diagnostic_item(sym::NonZero, adt.did())) =>
+        {
+            Some(Constructor::NonZero)
+        },
+        "from_u32_unchecked"
+            if self_ty.is_some_and(Ty::is_char) || match_def_path(cx, fn_id, &paths::CHAR_FROM_U32_UNCHECKED) =>
+        {
+            Some(Constructor::Char)
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,119 @@
+use clippy_utils::attrs::has_safety_section;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_trait_impl_item, span_contains_comment};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Block, BlockCheckMode, Body, Expr, ExprKind, FnDecl, FnSig, Safety, Stmt, StmtKind, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public safe functions whose body only calls an unsafe function in an `unsafe`
+    /// block, without a comment or a `# Safety` section explaining why this is sound.
+    ///
+    /// ### Why restrict this?
+    /// Such a wrapper promises that the safety requirements of the unsafe function hold for all
+    /// of its inputs. Thin wrappers make this easy to get wrong, and are a frequent source of
+    /// unsound safe APIs, so the reasoning should be written down.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # unsafe fn inner(_: usize) {}
+    /// pub fn wrapper(index: usize) {
+    ///     unsafe { inner(index) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # unsafe fn inner(_: usize) {}
+    /// pub fn wrapper(index: usize) {
+    ///     // SAFETY: `inner` accepts any index, it only requires the global buffer to be
+    ///     // initialized, which is done on startup.
+    ///     unsafe { inner(index) }
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNDOCUMENTED_SAFE_WRAPPER,
+    restriction,
+    "public safe function wrapping an unsafe call without justification"
+}
+
+declare_lint_pass!(UndocumentedSafeWrapper => [UNDOCUMENTED_SAFE_WRAPPER]);
+
+impl<'tcx> LateLintPass<'tcx> for UndocumentedSafeWrapper {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+        if let FnKind::ItemFn(_, _, header) | FnKind::Method(_, &FnSig { header, .. }) = kind
+            && !header.is_unsafe()
+            && !span.from_expansion()
+            && !in_external_macro(cx.sess(), span)
+            && cx.effective_visibilities.is_exported(def_id)
+            && !is_trait_impl_item(cx, hir_id)
+            && let ExprKind::Block(block, _) = body.value.kind
+            && let Some(unsafe_block) = single_expr(block)
+            && let ExprKind::Block(unsafe_block, _) = unsafe_block.kind
+            && unsafe_block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            && let Some(call) = single_expr(unsafe_block)
+            && let Some(callee) = unsafe_callee(cx, call)
+            && !span_contains_comment(cx.sess().source_map(), body.value.span)
+            && !has_safety_section(cx, hir_id)
+        {
+            span_lint_and_then(
+                cx,
+                UNDOCUMENTED_SAFE_WRAPPER,
+                cx.tcx.def_span(def_id),
+                "safe wrapper around an unsafe function without a justification",
+                |diag| {
+                    let name = cx.tcx.item_name(callee);
+                    diag.span_label(call.span, format!("`{name}` is called here"));
+                    diag.help(format!(
+                        "explain why every call of this function upholds the safety requirements of `{name}`, \
+                        in a `SAFETY` comment or a `# Safety` section of its documentation"
+                    ));
+                },
+            );
+        }
+    }
+}
+
+/// Returns the only statement or tail expression of the block.
+fn single_expr<'tcx>(block: &Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match (block.stmts, block.expr) {
+        ([], Some(tail)) => Some(tail),
+        (
+            [
+                Stmt {
+                    kind: StmtKind::Semi(expr),
+                    ..
+                },
+            ],
+            None,
+        ) => Some(expr),
+        _ => None,
+    }
+}
+
+/// Returns the unsafe function or method called by the expression, if any.
+fn unsafe_callee(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
+    let id = match expr.kind {
+        ExprKind::Call(callee, _) => match *cx.typeck_results().expr_ty(callee).kind() {
+            ty::FnDef(id, _) => id,
+            _ => return None,
+        },
+        ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id)?,
+        _ => return None,
+    };
+    (cx.tcx.fn_sig(id).skip_binder().safety() == Safety::Unsafe).then_some(id)
+}
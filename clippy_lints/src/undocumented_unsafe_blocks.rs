@@ -1,10 +1,11 @@
 use std::ops::ControlFlow;
 
 use clippy_config::Conf;
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::attrs::has_safety_section;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then, span_lint_hir_and_then};
 use clippy_utils::is_lint_allowed;
 use clippy_utils::source::walk_span_to_context;
-use clippy_utils::visitors::{Descend, for_each_expr};
+use clippy_utils::visitors::{Descend, contains_unsafe_op, for_each_expr};
 use hir::HirId;
 use rustc_data_structures::sync::Lrc;
 use rustc_hir as hir;
@@ -92,6 +93,115 @@ declare_clippy_lint! {
     "annotating safe code with a safety comment"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for statements in an `unsafe` block with several unsafe operations, which perform
+    /// unsafe operations without a `// SAFETY: ` comment of their own.
+    ///
+    /// The safety comment of the block itself, as required by `undocumented_unsafe_blocks`,
+    /// counts as the comment of the first statement with unsafe operations.
+    ///
+    /// ### Why restrict this?
+    /// A single safety comment for a block with several unsafe operations tends to justify only
+    /// some of them. Documenting each operation where it's performed makes it easier to check that
+    /// all of their safety requirements are upheld.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let ptr = [0u8; 2].as_mut_ptr();
+    /// // SAFETY: `ptr` is valid for writes.
+    /// unsafe {
+    ///     ptr.write(1);
+    ///     ptr.add(1).write(2);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let ptr = [0u8; 2].as_mut_ptr();
+    /// // SAFETY: `ptr` is valid for writes.
+    /// unsafe {
+    ///     ptr.write(1);
+    ///     // SAFETY: `ptr` points to an array of two bytes, so `ptr.add(1)` is in bounds.
+    ///     ptr.add(1).write(2);
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNDOCUMENTED_UNSAFE_OPERATION,
+    restriction,
+    "performing an unsafe operation without explaining why it is safe"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe impl` blocks without a `// SAFETY: ` comment or a `# Safety` section in
+    /// their documentation, explaining why the type upholds the requirements of the unsafe trait.
+    ///
+    /// `undocumented_unsafe_blocks` also checks unsafe impls. This lint only checks them, and only
+    /// warns when `undocumented_unsafe_blocks` is allowed, so that it can be enabled on its own.
+    ///
+    /// ### Why restrict this?
+    /// Unsafe traits like `Send`, `Sync` or `GlobalAlloc` have requirements which the compiler
+    /// can't check. An undocumented implementation is difficult to review, and easily becomes
+    /// unsound when the type changes.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// struct Buffer(*mut u8);
+    ///
+    /// unsafe impl Send for Buffer {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Buffer(*mut u8);
+    ///
+    /// // SAFETY: `Buffer` owns the allocation it points to, and never shares it.
+    /// unsafe impl Send for Buffer {}
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNDOCUMENTED_UNSAFE_IMPLS,
+    restriction,
+    "implementing an unsafe trait without explaining why it is safe"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for reads of union fields without a `// SAFETY: ` comment on the statement
+    /// performing the read.
+    ///
+    /// The safety comment of an `unsafe` block counts when the read is its only statement.
+    ///
+    /// ### Why restrict this?
+    /// Reading a union field is only sound if the field is the one which was last written, or if
+    /// its bytes are valid for its type. These reads are easy to overlook inside larger `unsafe`
+    /// blocks, so the active field should be documented where it's read.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// union IntOrFloat {
+    ///     int: u32,
+    ///     float: f32,
+    /// }
+    ///
+    /// let value = IntOrFloat { int: 1 };
+    /// let int = unsafe { value.int };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// union IntOrFloat {
+    ///     int: u32,
+    ///     float: f32,
+    /// }
+    ///
+    /// let value = IntOrFloat { int: 1 };
+    /// // SAFETY: `int` is the active field, it was initialized above.
+    /// let int = unsafe { value.int };
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNDOCUMENTED_UNION_READ,
+    restriction,
+    "reading a union field without explaining which field is active"
+}
+
 pub struct UndocumentedUnsafeBlocks {
     accept_comment_above_statement: bool,
     accept_comment_above_attributes: bool,
@@ -106,9 +216,155 @@ impl UndocumentedUnsafeBlocks {
     }
 }
 
-impl_lint_pass!(UndocumentedUnsafeBlocks => [UNDOCUMENTED_UNSAFE_BLOCKS, UNNECESSARY_SAFETY_COMMENT]);
+impl_lint_pass!(UndocumentedUnsafeBlocks => [
+    UNDOCUMENTED_UNSAFE_BLOCKS,
+    UNNECESSARY_SAFETY_COMMENT,
+    UNDOCUMENTED_UNSAFE_OPERATION,
+    UNDOCUMENTED_UNSAFE_IMPLS,
+    UNDOCUMENTED_UNION_READ,
+]);
+
+impl UndocumentedUnsafeBlocks {
+    /// Lints the statements with unsafe operations in a block with several of them which don't
+    /// have a safety comment, except for the first one if the block itself has one.
+    fn check_unsafe_operations<'tcx>(&self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        let mut units: Vec<(HirId, Span)> = block
+            .stmts
+            .iter()
+            .filter(|stmt| contains_unsafe_op(cx, *stmt))
+            .map(|stmt| (stmt.hir_id, stmt.span))
+            .chain(
+                block
+                    .expr
+                    .filter(|tail| contains_unsafe_op(cx, *tail))
+                    .map(|tail| (tail.hir_id, tail.span)),
+            )
+            .map(|(hir_id, span)| (hir_id, walk_span_to_context(span, block.span.ctxt()).unwrap_or(span)))
+            .collect();
+        // The statements of a single macro call share its safety comment.
+        units.dedup_by_key(|(_, span)| *span);
+        if units.len() < 2 {
+            return;
+        }
+
+        for (i, &(hir_id, span)) in units.iter().enumerate() {
+            if !matches!(stmt_has_safety_comment(cx, span, hir_id), HasSafetyComment::No)
+                || (i == 0
+                    && (block_has_safety_comment(cx, block.span)
+                        || block_parents_have_safety_comment(
+                            self.accept_comment_above_statement,
+                            self.accept_comment_above_attributes,
+                            cx,
+                            block.hir_id,
+                        )))
+            {
+                continue;
+            }
+
+            let source_map = cx.tcx.sess.source_map();
+            let span = if source_map.is_multiline(span) {
+                source_map.span_until_char(span, '\n')
+            } else {
+                span
+            };
+
+            span_lint_hir_and_then(
+                cx,
+                UNDOCUMENTED_UNSAFE_OPERATION,
+                hir_id,
+                span,
+                "unsafe operation missing a safety comment",
+                |diag| {
+                    diag.help("consider adding a safety comment on the preceding line");
+                },
+            );
+        }
+    }
+
+    /// Lints a read of a union field whose statement doesn't have a safety comment.
+    fn check_union_read<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>, base: &'tcx hir::Expr<'tcx>) {
+        // Find the statement or tail expression containing the read.
+        let mut child = expr.hir_id;
+        let Some(block) = cx.tcx.hir().parent_iter(expr.hir_id).find_map(|(id, node)| {
+            if let Node::Block(block) = node {
+                Some(block)
+            } else {
+                child = id;
+                None
+            }
+        }) else {
+            return;
+        };
+        let span = cx.tcx.hir().span(child);
+        if !matches!(stmt_has_safety_comment(cx, span, child), HasSafetyComment::No) {
+            return;
+        }
+        // The comment of an `unsafe` block covers its only statement.
+        if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            && block.stmts.len() + usize::from(block.expr.is_some()) == 1
+            && (block_has_safety_comment(cx, block.span)
+                || block_parents_have_safety_comment(
+                    self.accept_comment_above_statement,
+                    self.accept_comment_above_attributes,
+                    cx,
+                    block.hir_id,
+                ))
+        {
+            return;
+        }
+
+        let union_ty = cx.typeck_results().expr_ty_adjusted(base).peel_refs();
+        span_lint_and_help(
+            cx,
+            UNDOCUMENTED_UNION_READ,
+            expr.span,
+            "union field read missing a safety comment",
+            None,
+            format!(
+                "consider adding a safety comment on the preceding line, explaining which field of `{union_ty}` is active"
+            ),
+        );
+    }
+}
+
+/// Checks whether the place is only used to create a raw pointer, e.g. with `addr_of!`, or
+/// discarded with `let _`, neither of which reads it.
+fn is_place_unread<'tcx>(cx: &LateContext<'tcx>, mut place: &'tcx hir::Expr<'tcx>) -> bool {
+    loop {
+        match cx.tcx.parent_hir_node(place.hir_id) {
+            Node::Expr(parent) => match parent.kind {
+                hir::ExprKind::Field(base, _) | hir::ExprKind::Index(base, ..) if base.hir_id == place.hir_id => {
+                    place = parent;
+                },
+                hir::ExprKind::AddrOf(hir::BorrowKind::Raw, ..) => return true,
+                _ => return false,
+            },
+            Node::LetStmt(local) => return matches!(local.pat.kind, hir::PatKind::Wild),
+            _ => return false,
+        }
+    }
+}
 
 impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Field(base, _) = expr.kind
+            && !expr.span.from_expansion()
+            && !is_lint_allowed(cx, UNDOCUMENTED_UNION_READ, expr.hir_id)
+            && cx.typeck_results().expr_ty_adjusted(base).peel_refs().is_union()
+            // Writing to a union field is safe
+            && !matches!(
+                cx.tcx.parent_hir_node(expr.hir_id),
+                Node::Expr(hir::Expr {
+                    kind: hir::ExprKind::Assign(lhs, ..),
+                    ..
+                }) if lhs.hir_id == expr.hir_id
+            )
+            && !is_place_unread(cx, expr)
+        {
+            self.check_union_read(cx, expr, base);
+        }
+    }
+
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
         if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
             && !in_external_macro(cx.tcx.sess, block.span)
@@ -141,6 +397,14 @@ impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
             );
         }
 
+        if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            && !block.span.from_expansion()
+            && !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_OPERATION, block.hir_id)
+            && !is_unsafe_from_proc_macro(cx, block.span)
+        {
+            self.check_unsafe_operations(cx, block);
+        }
+
         if let Some(tail) = block.expr
             && !is_lint_allowed(cx, UNNECESSARY_SAFETY_COMMENT, tail.hir_id)
             && !in_external_macro(cx.tcx.sess, tail.span)
@@ -204,27 +468,7 @@ impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
         match (&item.kind, item_has_safety_comment) {
             // lint unsafe impl without safety comment
             (ItemKind::Impl(impl_), HasSafetyComment::No) if impl_.safety == hir::Safety::Unsafe => {
-                if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, item.hir_id())
-                    && !is_unsafe_from_proc_macro(cx, item.span)
-                {
-                    let source_map = cx.tcx.sess.source_map();
-                    let span = if source_map.is_multiline(item.span) {
-                        source_map.span_until_char(item.span, '\n')
-                    } else {
-                        item.span
-                    };
-
-                    #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
-                    span_lint_and_then(
-                        cx,
-                        UNDOCUMENTED_UNSAFE_BLOCKS,
-                        span,
-                        "unsafe impl missing a safety comment",
-                        |diag| {
-                            diag.help("consider adding a safety comment on the preceding line");
-                        },
-                    );
-                }
+                check_unsafe_impl(cx, item, impl_);
             },
             // lint safe impl with unnecessary safety comment
             (ItemKind::Impl(impl_), HasSafetyComment::Yes(pos)) if impl_.safety == hir::Safety::Safe => {
@@ -287,6 +531,50 @@ impl<'tcx> LateLintPass<'tcx> for UndocumentedUnsafeBlocks {
     }
 }
 
+/// Lints an unsafe impl without a safety comment, with `undocumented_unsafe_blocks` or
+/// `undocumented_unsafe_impls`.
+fn check_unsafe_impl(cx: &LateContext<'_>, item: &hir::Item<'_>, impl_: &hir::Impl<'_>) {
+    if is_unsafe_from_proc_macro(cx, item.span) {
+        return;
+    }
+    let source_map = cx.tcx.sess.source_map();
+    let span = if source_map.is_multiline(item.span) {
+        source_map.span_until_char(item.span, '\n')
+    } else {
+        item.span
+    };
+
+    if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_BLOCKS, item.hir_id()) {
+        #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
+        span_lint_and_then(
+            cx,
+            UNDOCUMENTED_UNSAFE_BLOCKS,
+            span,
+            "unsafe impl missing a safety comment",
+            |diag| {
+                diag.help("consider adding a safety comment on the preceding line");
+            },
+        );
+    } else if !is_lint_allowed(cx, UNDOCUMENTED_UNSAFE_IMPLS, item.hir_id())
+        && !has_safety_section(cx, item.hir_id())
+        && let Some(trait_ref) = &impl_.of_trait
+        && let Some(trait_id) = trait_ref.trait_def_id()
+    {
+        let trait_name = cx.tcx.item_name(trait_id);
+        span_lint_and_help(
+            cx,
+            UNDOCUMENTED_UNSAFE_IMPLS,
+            span,
+            format!("`unsafe impl {trait_name}` missing a safety comment"),
+            None,
+            format!(
+                "consider explaining why the requirements of `{trait_name}` are upheld, \
+                in a safety comment on the preceding line or a `# Safety` section of its documentation"
+            ),
+        );
+    }
+}
+
 fn expr_has_unnecessary_safety_comment<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx hir::Expr<'tcx>,
@@ -412,7 +700,7 @@ fn is_branchy(expr: &hir::Expr<'_>) -> bool {
 }
 
 /// Checks if the lines immediately preceding the block contain a safety comment.
-fn block_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
+pub(crate) fn block_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
     // This intentionally ignores text before the start of a function so something like:
     // ```
     //     // SAFETY: reason
@@ -634,6 +922,19 @@ fn get_body_search_span(cx: &LateContext<'_>) -> Option<Span> {
 }
 
 fn span_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
+    !matches!(span_safety_comment(cx, span), HasSafetyComment::No)
+}
+
+/// Returns the start of the safety comment on the lines immediately preceding the block, if it
+/// isn't from a macro expansion.
+pub(crate) fn block_safety_comment_start(cx: &LateContext<'_>, span: Span) -> Option<BytePos> {
+    match span_safety_comment(cx, span) {
+        HasSafetyComment::Yes(start) => Some(start),
+        HasSafetyComment::No | HasSafetyComment::Maybe => None,
+    }
+}
+
+fn span_safety_comment(cx: &LateContext<'_>, span: Span) -> HasSafetyComment {
     let source_map = cx.sess().source_map();
     let ctxt = span.ctxt();
     if ctxt.is_root()
@@ -648,19 +949,23 @@ fn span_has_safety_comment(cx: &LateContext<'_>, span: Span) -> bool {
             // Get the text from the start of function body to the unsafe block.
             //     fn foo() { some_stuff; unsafe { stuff }; other_stuff; }
             //              ^-------------^
-            body_line.line < unsafe_line.line
-                && text_has_safety_comment(
+            if body_line.line < unsafe_line.line
+                && let Some(start) = text_has_safety_comment(
                     src,
                     &unsafe_line.sf.lines()[body_line.line + 1..=unsafe_line.line],
                     unsafe_line.sf.start_pos,
                 )
-                .is_some()
+            {
+                HasSafetyComment::Yes(start)
+            } else {
+                HasSafetyComment::No
+            }
         } else {
             // Problem getting source text. Pretend a comment was found.
-            true
+            HasSafetyComment::Maybe
         }
     } else {
-        false
+        HasSafetyComment::No
     }
 }
 
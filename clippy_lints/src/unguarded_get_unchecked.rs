@@ -0,0 +1,150 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{eq_expr_value, higher, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `get_unchecked` and `get_unchecked_mut` on slices and `str` without a
+    /// visible bounds check of the index in the same function, such as a comparison, an
+    /// assertion, an indexing or a `for` loop over the indices.
+    ///
+    /// ### Why restrict this?
+    /// An out of bounds index is undefined behavior. When nothing near the call shows why the
+    /// index is in bounds, the invariant is easily broken by later changes, and the
+    /// `get_unchecked` is often a premature optimization which the compiler would have performed
+    /// anyway.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn first_byte(bytes: &[u8], i: usize) -> u8 {
+    ///     unsafe { *bytes.get_unchecked(i) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn first_byte(bytes: &[u8], i: usize) -> u8 {
+    ///     debug_assert!(i < bytes.len());
+    ///     unsafe { *bytes.get_unchecked(i) }
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNGUARDED_GET_UNCHECKED,
+    restriction,
+    "`get_unchecked` without a visible bounds check"
+}
+
+declare_lint_pass!(UnguardedGetUnchecked => [UNGUARDED_GET_UNCHECKED]);
+
+impl<'tcx> LateLintPass<'tcx> for UnguardedGetUnchecked {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, recv, [index], _) = expr.kind
+            && matches!(path.ident.name.as_str(), "get_unchecked" | "get_unchecked_mut")
+            && !expr.span.from_expansion()
+            && let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs()
+            && (recv_ty.is_slice() || recv_ty.is_str())
+            && !has_bounds_check(cx, expr, recv, index)
+        {
+            span_lint_and_then(
+                cx,
+                UNGUARDED_GET_UNCHECKED,
+                expr.span,
+                format!("`{}` without a visible bounds check", path.ident),
+                |diag| {
+                    let is_range = higher::Range::hir(index).is_some();
+                    let recv = snippet(cx, recv.span, "..");
+                    let index = snippet(cx, index.span, "..");
+                    let safe = format!("`{recv}[{index}]` or `{recv}.get({index})`");
+                    if is_range {
+                        diag.help(format!("consider using {safe}"));
+                    } else {
+                        diag.help(format!(
+                            "if the index is known to be in bounds, document it with `debug_assert!({index} < {recv}.len())`"
+                        ));
+                        diag.help(format!("otherwise, consider using {safe}"));
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Checks whether the body of the call checks the index against the length of the receiver
+/// before the call, indexes the receiver with it, or runs the call in a `for` loop over the
+/// receiver binding the index.
+fn has_bounds_check<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, recv: &Expr<'_>, index: &'tcx Expr<'tcx>) -> bool {
+    let owner = cx
+        .tcx
+        .typeck_root_def_id(cx.tcx.hir().enclosing_body_owner(call.hir_id).to_def_id());
+    let Some(owner) = owner.as_local() else {
+        return false;
+    };
+    let body = cx.tcx.hir().body_owned_by(owner);
+    // The bounds of a range are checked instead of the range itself.
+    let parts = match higher::Range::hir(index) {
+        Some(range) => range.start.into_iter().chain(range.end).collect(),
+        None => vec![index],
+    };
+    let index_locals: Vec<_> = parts.iter().filter_map(|part| path_to_local(part)).collect();
+    // A constant index is checked by comparing the length of the receiver instead.
+    let is_const_index = parts.iter().all(|part| matches!(part.kind, ExprKind::Lit(_)));
+    let mentions = |expr: &'tcx Expr<'tcx>, pred: &dyn Fn(&Expr<'_>) -> bool| {
+        for_each_expr(cx, expr, |e| {
+            if pred(e) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_some()
+    };
+    let is_index = |e: &Expr<'_>| {
+        parts.iter().any(|part| match path_to_local(part) {
+            Some(id) => path_to_local_id(e, id),
+            None => eq_expr_value(cx, e, part),
+        })
+    };
+    let is_recv_len = |e: &Expr<'_>| {
+        matches!(e.kind, ExprKind::MethodCall(path, receiver, [], _)
+            if path.ident.name == sym::len && eq_expr_value(cx, receiver, recv))
+    };
+    let is_recv = |e: &Expr<'_>| eq_expr_value(cx, e, recv);
+
+    for_each_expr(cx, body.value, |e| {
+        let is_check = if let Some(for_loop) = higher::ForLoop::hir(e) {
+            for_loop.span.contains(call.span) && mentions(for_loop.arg, &is_recv) && {
+                let mut binds = false;
+                for_loop
+                    .pat
+                    .each_binding_or_first(&mut |_, hir_id, _, _| binds |= index_locals.contains(&hir_id));
+                binds
+            }
+        } else if e.span.lo() < call.span.lo() {
+            match e.kind {
+                ExprKind::Binary(op, lhs, rhs) => {
+                    matches!(op.node, BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge)
+                        && (mentions(lhs, &is_recv_len) || mentions(rhs, &is_recv_len))
+                        && (is_const_index || mentions(lhs, &is_index) || mentions(rhs, &is_index))
+                },
+                ExprKind::Index(indexed, idx, _) => !is_const_index && is_recv(indexed) && mentions(idx, &is_index),
+                ExprKind::MethodCall(path, receiver, [], _) => {
+                    is_const_index && path.ident.name.as_str() == "is_empty" && is_recv(receiver)
+                },
+                _ => false,
+            }
+        } else {
+            false
+        };
+        if is_check {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
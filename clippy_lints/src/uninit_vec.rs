@@ -1,8 +1,10 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_then};
 use clippy_utils::higher::{VecInitKind, get_vec_init_kind};
 use clippy_utils::ty::{is_type_diagnostic_item, is_uninit_value_valid_for_ty};
+use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{SpanlessEq, is_integer_literal, is_lint_allowed, path_to_local_id, peel_hir_expr_while};
-use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, PathSegment, Stmt, StmtKind};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, Node, PatKind, PathSegment, Stmt, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
@@ -58,7 +60,41 @@ declare_clippy_lint! {
     "Vec with uninitialized data"
 }
 
-declare_lint_pass!(UninitVec => [UNINIT_VEC]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Vec::set_len()` calls without any sign of the elements being initialized
+    /// before in the same function, such as writes through `spare_capacity_mut()` or
+    /// `as_mut_ptr()`, or a call to `ptr::copy_nonoverlapping()`.
+    ///
+    /// ### Why is this bad?
+    /// If `set_len()` extends the length of the `Vec` over uninitialized elements, any later
+    /// access to them is undefined behavior.
+    ///
+    /// ### Known Problems
+    /// The calls to `set_len()` which shrink the `Vec` are only recognized when the new length is
+    /// `0` or a subtraction. The initialization is not tracked across functions.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn extend_zeroed(vec: &mut Vec<u8>, additional: usize) {
+    ///     vec.reserve(additional);
+    ///     // ..
+    ///     unsafe { vec.set_len(vec.len() + additional) };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn extend_zeroed(vec: &mut Vec<u8>, additional: usize) {
+    ///     vec.resize(vec.len() + additional, 0);
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub SET_LEN_WITHOUT_INIT,
+    pedantic,
+    "`Vec::set_len()` without a visible initialization of the elements"
+}
+
+declare_lint_pass!(UninitVec => [UNINIT_VEC, SET_LEN_WITHOUT_INIT]);
 
 // FIXME: update to a visitor-based implementation.
 // Threads: https://github.com/rust-lang/rust-clippy/pull/7682#discussion_r710998368
@@ -76,6 +112,110 @@ impl<'tcx> LateLintPass<'tcx> for UninitVec {
             }
         }
     }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some((set_len_self, call_span)) = extract_set_len_self(cx, expr)
+            && call_span == expr.span
+            && !in_external_macro(cx.tcx.sess, expr.span)
+            && let ExprKind::MethodCall(_, _, [new_len], _) = expr.kind
+            && !matches!(new_len.kind, ExprKind::Binary(op, ..) if op.node == BinOpKind::Sub)
+            && let ty::Adt(_, args) = cx.typeck_results().expr_ty(set_len_self).peel_refs().kind()
+            && !is_uninit_value_valid_for_ty(cx, args.type_at(0))
+            && !follows_init_or_reserve(cx, expr, set_len_self)
+            && !has_init_before(cx, expr, set_len_self)
+        {
+            span_lint_and_help(
+                cx,
+                SET_LEN_WITHOUT_INIT,
+                expr.span,
+                "calling `set_len()` without a visible initialization of the elements",
+                None,
+                "initialize the new elements through `spare_capacity_mut()` before calling `set_len()`, \
+                or use `resize()`",
+            );
+        }
+    }
+}
+
+/// Checks whether the `set_len()` call directly follows the allocation of the `Vec`, which is
+/// linted by `UNINIT_VEC`.
+fn follows_init_or_reserve<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, set_len_self: &'tcx Expr<'tcx>) -> bool {
+    let mut child = call.hir_id;
+    for (id, node) in cx.tcx.hir().parent_iter(call.hir_id) {
+        match node {
+            Node::Block(block) => {
+                let pos = block
+                    .stmts
+                    .iter()
+                    .position(|stmt| stmt.hir_id == child)
+                    .or_else(|| block.expr.filter(|e| e.hir_id == child).map(|_| block.stmts.len()));
+                if let Some(pos) = pos
+                    && pos > 0
+                    && let Some(vec) = extract_init_or_reserve_target(cx, &block.stmts[pos - 1])
+                    && vec.location.eq_expr(cx, set_len_self)
+                {
+                    return true;
+                }
+            },
+            Node::Stmt(_)
+            | Node::Expr(Expr {
+                kind: ExprKind::Block(..),
+                ..
+            }) => {},
+            _ => return false,
+        }
+        child = id;
+    }
+    false
+}
+
+/// Checks whether the body of the `set_len()` call writes to the buffer of the `Vec`, or copies
+/// memory, before the call.
+fn has_init_before<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, set_len_self: &'tcx Expr<'tcx>) -> bool {
+    let owner = cx
+        .tcx
+        .typeck_root_def_id(cx.tcx.hir().enclosing_body_owner(call.hir_id).to_def_id());
+    let Some(owner) = owner.as_local() else {
+        return true;
+    };
+    let body = cx.tcx.hir().body_owned_by(owner);
+    for_each_expr(cx, body.value, |e| {
+        if e.span.lo() >= call.span.lo() {
+            return ControlFlow::Continue(());
+        }
+        let is_init = match e.kind {
+            ExprKind::MethodCall(path, self_expr, ..) => {
+                matches!(path.ident.as_str(), "spare_capacity_mut" | "as_mut_ptr")
+                    && SpanlessEq::new(cx).eq_expr(self_expr.peel_borrows(), set_len_self.peel_borrows())
+            },
+            ExprKind::Call(func, _) => {
+                if let ExprKind::Path(ref path) = func.kind
+                    && let Some(def_id) = cx.qpath_res(path, func.hir_id).opt_def_id()
+                {
+                    matches!(
+                        cx.tcx.get_diagnostic_name(def_id),
+                        Some(
+                            sym::ptr_copy
+                                | sym::ptr_copy_nonoverlapping
+                                | sym::ptr_write
+                                | sym::ptr_write_bytes
+                                | sym::ptr_write_unaligned
+                                | sym::ptr_write_volatile
+                        )
+                    )
+                } else {
+                    false
+                }
+            },
+            _ => false,
+        };
+        if is_init {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
 }
 
 fn handle_uninit_vec_pair<'tcx>(
@@ -0,0 +1,81 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{is_in_test_function, matches_any_path};
+use rustc_hir::{Block, BlockCheckMode, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe` blocks in `#[test]` functions.
+    ///
+    /// Modules listed in the `allowed-unsafe-test-modules` configuration, such as modules of test
+    /// helpers for FFI, aren't checked.
+    ///
+    /// ### Why restrict this?
+    /// Some projects require tests to only exercise the safe API, so that the tests show how the
+    /// crate is meant to be used, and so that they can't hide unsoundness behind `unsafe` code of
+    /// their own.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[test]
+    /// fn first() {
+    ///     let v = vec![1, 2, 3];
+    ///     assert_eq!(unsafe { *v.get_unchecked(0) }, 1);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[test]
+    /// fn first() {
+    ///     let v = vec![1, 2, 3];
+    ///     assert_eq!(v[0], 1);
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNSAFE_BLOCK_IN_TEST,
+    restriction,
+    "`unsafe` blocks in `#[test]` functions"
+}
+
+impl_lint_pass!(UnsafeBlockInTest => [UNSAFE_BLOCK_IN_TEST]);
+
+pub struct UnsafeBlockInTest {
+    allowed_modules: Vec<Vec<&'static str>>,
+}
+
+impl UnsafeBlockInTest {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allowed_modules: conf
+                .allowed_unsafe_test_modules
+                .iter()
+                .map(|path| path.split("::").collect())
+                .collect(),
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnsafeBlockInTest {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+            && !block.span.from_expansion()
+            && is_in_test_function(cx.tcx, block.hir_id)
+            && !matches_any_path(
+                cx,
+                cx.tcx.parent_module(block.hir_id).to_def_id(),
+                &self.allowed_modules,
+            )
+        {
+            span_lint_and_help(
+                cx,
+                UNSAFE_BLOCK_IN_TEST,
+                block.span,
+                "`unsafe` block in a test",
+                None,
+                "consider testing the safe API instead",
+            );
+        }
+    }
+}
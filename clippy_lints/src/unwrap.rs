@@ -48,10 +48,12 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calls of `unwrap[_err]()` that will always fail.
+    /// Checks for calls of `unwrap[_err]()` that will always fail, and of
+    /// `unwrap[_err]_unchecked()` that are always undefined behavior.
     ///
     /// ### Why is this bad?
-    /// If panicking is desired, an explicit `panic!()` should be used.
+    /// If panicking is desired, an explicit `panic!()` should be used. Calling
+    /// `unwrap_unchecked()` on the wrong variant is undefined behavior.
     ///
     /// ### Known problems
     /// This lint only checks `if` conditions not assignments.
@@ -73,6 +75,39 @@ declare_clippy_lint! {
     "checks for calls of `unwrap[_err]()` that will always fail"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls of `unwrap[_err]_unchecked()` right after checking that they can't fail.
+    ///
+    /// ### Why is this bad?
+    /// The check already makes `unwrap` or `expect` free, as the compiler removes their own check
+    /// of the variant, so the `unsafe` code buys nothing. Using `if let` or `match` is more
+    /// idiomatic still.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let option = Some(0);
+    /// # fn do_something_with(_x: usize) {}
+    /// if option.is_some() {
+    ///     do_something_with(unsafe { option.unwrap_unchecked() })
+    /// }
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```no_run
+    /// # let option = Some(0);
+    /// # fn do_something_with(_x: usize) {}
+    /// if let Some(value) = option {
+    ///     do_something_with(value)
+    /// }
+    /// ```
+    #[clippy::version = "1.85.0"]
+    pub UNNECESSARY_UNWRAP_UNCHECKED,
+    complexity,
+    "checks for calls of `unwrap[_err]_unchecked()` after checking that they cannot fail"
+}
+
 /// Visitor that keeps track of which variables are unwrappable.
 struct UnwrappableVariablesVisitor<'a, 'tcx> {
     unwrappables: Vec<UnwrapInfo<'tcx>>,
@@ -266,6 +301,20 @@ impl<'tcx> UnwrappableVariablesVisitor<'_, 'tcx> {
     }
 }
 
+/// Returns the receiver of the `map` calls at the end of the expression, as they don't change the
+/// variant of an `Option` or a `Result`.
+fn consume_map<'tcx>(cx: &LateContext<'_>, mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::MethodCall(path, recv, [_], _) = expr.kind
+        && path.ident.name == sym::map
+        && let ty = cx.typeck_results().expr_ty(recv)
+        && (is_type_diagnostic_item(cx, ty, sym::Option) || is_type_diagnostic_item(cx, ty, sym::Result))
+    {
+        expr = recv;
+    }
+    expr
+}
+
+#[derive(Clone, Copy)]
 enum AsRefKind {
     AsRef,
     AsMut,
@@ -304,10 +353,13 @@ impl<'tcx> Visitor<'tcx> for UnwrappableVariablesVisitor<'_, 'tcx> {
         } else {
             // find `unwrap[_err]()` or `expect("...")` calls:
             if let ExprKind::MethodCall(method_name, self_arg, ..) = expr.kind
+                && let name = method_name.ident.name
+                && let is_unchecked = [sym!(unwrap_unchecked), sym!(unwrap_err_unchecked)].contains(&name)
+                && let self_arg = if is_unchecked { consume_map(self.cx, self_arg) } else { self_arg }
                 && let (self_arg, as_ref_kind) = consume_option_as_ref(self_arg)
                 && let Some(id) = path_to_local(self_arg)
-                && [sym::unwrap, sym::expect, sym!(unwrap_err)].contains(&method_name.ident.name)
-                && let call_to_unwrap = [sym::unwrap, sym::expect].contains(&method_name.ident.name)
+                && (is_unchecked || [sym::unwrap, sym::expect, sym!(unwrap_err)].contains(&name))
+                && let call_to_unwrap = [sym::unwrap, sym::expect, sym!(unwrap_unchecked)].contains(&name)
                 && let Some(unwrappable) = self.unwrappables.iter()
                     .find(|u| u.local_id == id)
                 // Span contexts should not differ with the conditional branch
@@ -315,7 +367,25 @@ impl<'tcx> Visitor<'tcx> for UnwrappableVariablesVisitor<'_, 'tcx> {
                 && unwrappable.branch.span.ctxt() == span_ctxt
                 && unwrappable.check.span.ctxt() == span_ctxt
             {
-                if call_to_unwrap == unwrappable.safe_to_unwrap {
+                if is_unchecked {
+                    if call_to_unwrap == unwrappable.safe_to_unwrap {
+                        lint_unwrap_unchecked(self.cx, expr, method_name, unwrappable, call_to_unwrap, as_ref_kind);
+                    } else {
+                        span_lint_hir_and_then(
+                            self.cx,
+                            PANICKING_UNWRAP,
+                            expr.hir_id,
+                            expr.span,
+                            format!(
+                                "this call to `{}()` is always undefined behavior",
+                                method_name.ident.name
+                            ),
+                            |diag| {
+                                diag.span_label(unwrappable.check.span, "because of this check");
+                            },
+                        );
+                    }
+                } else if call_to_unwrap == unwrappable.safe_to_unwrap {
                     let is_entire_condition = unwrappable.is_entire_condition;
                     let unwrappable_variable_name = self.cx.tcx.hir().name(unwrappable.local_id);
                     let suggested_pattern = if call_to_unwrap {
@@ -380,7 +450,50 @@ impl<'tcx> Visitor<'tcx> for UnwrappableVariablesVisitor<'_, 'tcx> {
     }
 }
 
-declare_lint_pass!(Unwrap => [PANICKING_UNWRAP, UNNECESSARY_UNWRAP]);
+/// Lints a call to `unwrap[_err]_unchecked()` which can't fail because of the check.
+fn lint_unwrap_unchecked(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    method_name: &PathSegment<'_>,
+    unwrappable: &UnwrapInfo<'_>,
+    call_to_unwrap: bool,
+    as_ref_kind: Option<AsRefKind>,
+) {
+    let unwrappable_variable_name = cx.tcx.hir().name(unwrappable.local_id);
+    let check_name = unwrappable.check_name.ident.as_str();
+    let (suggested_pattern, safe_method) = if call_to_unwrap {
+        (unwrappable.kind.success_variant_pattern(), "unwrap")
+    } else {
+        (unwrappable.kind.error_variant_pattern(), "unwrap_err")
+    };
+
+    span_lint_hir_and_then(
+        cx,
+        UNNECESSARY_UNWRAP_UNCHECKED,
+        expr.hir_id,
+        expr.span,
+        format!(
+            "called `{}` on `{unwrappable_variable_name}` after checking its variant with `{check_name}`",
+            method_name.ident.name,
+        ),
+        |diag| {
+            diag.span_label(unwrappable.check.span, "the check is happening here");
+            diag.help(format!(
+                "try using `if let {suggested_pattern} = {borrow_prefix}{unwrappable_variable_name}`, or `match`",
+                borrow_prefix = match as_ref_kind {
+                    Some(AsRefKind::AsRef) => "&",
+                    Some(AsRefKind::AsMut) => "&mut ",
+                    None => "",
+                },
+            ));
+            diag.help(format!(
+                "otherwise, `{safe_method}` is as fast here, as the compiler removes its check after `{check_name}`"
+            ));
+        },
+    );
+}
+
+declare_lint_pass!(Unwrap => [PANICKING_UNWRAP, UNNECESSARY_UNWRAP, UNNECESSARY_UNWRAP_UNCHECKED]);
 
 impl<'tcx> LateLintPass<'tcx> for Unwrap {
     fn check_fn(
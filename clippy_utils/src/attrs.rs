@@ -1,5 +1,6 @@
 use rustc_ast::{ast, attr};
 use rustc_errors::Applicability;
+use rustc_hir::HirId;
 use rustc_lexer::TokenKind;
 use rustc_lint::LateContext;
 use rustc_middle::ty::{AdtDef, TyCtxt};
@@ -202,3 +203,19 @@ pub fn span_contains_cfg(cx: &LateContext<'_>, s: Span) -> bool {
         false
     })
 }
+
+/// Checks if the doc comments of the item have a `# Safety` section.
+pub fn has_safety_section(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    cx.tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .filter_map(ast::Attribute::doc_str)
+        .any(|doc| {
+            doc.as_str().lines().any(|line| {
+                line.trim()
+                    .strip_prefix('#')
+                    .is_some_and(|heading| heading.trim_start_matches('#').trim() == "Safety")
+            })
+        })
+}
@@ -1967,6 +1967,32 @@ pub fn match_def_path(cx: &LateContext<'_>, did: DefId, syms: &[&str]) -> bool {
     syms.iter().map(|x| Symbol::intern(x)).eq(path.iter().copied())
 }
 
+/// Checks whether the definition matches any of the paths, which may end with a `*` matching
+/// everything inside of a module and begin with `crate` to refer to the current crate.
+pub fn matches_any_path(cx: &LateContext<'_>, id: DefId, paths: &[Vec<&str>]) -> bool {
+    if paths.is_empty() {
+        return false;
+    }
+    let def_path = cx.get_def_path(id);
+    paths.iter().any(|path| {
+        let (path, is_glob) = match path.split_last() {
+            Some((&"*", module)) => (module, true),
+            _ => (&path[..], false),
+        };
+        (if is_glob {
+            def_path.len() >= path.len()
+        } else {
+            def_path.len() == path.len()
+        }) && def_path.iter().zip(path).enumerate().all(|(i, (segment, expected))| {
+            if i == 0 && *expected == "crate" {
+                id.is_local()
+            } else {
+                segment.as_str() == *expected
+            }
+        })
+    })
+}
+
 /// Checks if the given `DefId` matches the `libc` item.
 pub fn match_libc_symbol(cx: &LateContext<'_>, did: DefId, name: &str) -> bool {
     let path = cx.get_def_path(did);
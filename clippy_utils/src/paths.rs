@@ -29,9 +29,14 @@ pub const SYNTAX_CONTEXT: [&str; 3] = ["rustc_span", "hygiene", "SyntaxContext"]
 
 // Paths in `core`/`alloc`/`std`. This should be avoided and cleaned up by adding diagnostic items.
 pub const ABORT: [&str; 3] = ["std", "process", "abort"];
+pub const ALLOC_MODULE: [&str; 2] = ["alloc", "alloc"];
+pub const CATCH_UNWIND: [&str; 3] = ["std", "panic", "catch_unwind"];
+pub const CHAR_FROM_U32_UNCHECKED: [&str; 3] = ["core", "char", "from_u32_unchecked"];
 pub const CHILD: [&str; 3] = ["std", "process", "Child"];
 pub const CHILD_ID: [&str; 4] = ["std", "process", "Child", "id"];
 pub const CHILD_KILL: [&str; 4] = ["std", "process", "Child", "kill"];
+pub const GLOBAL_ALLOC: [&str; 4] = ["core", "alloc", "global", "GlobalAlloc"];
+pub const HINT_UNREACHABLE_UNCHECKED: [&str; 3] = ["core", "hint", "unreachable_unchecked"];
 pub const PANIC_ANY: [&str; 3] = ["std", "panic", "panic_any"];
 
 // Paths in clippy itself
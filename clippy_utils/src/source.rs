@@ -5,7 +5,7 @@
 use rustc_ast::{LitKind, StrStyle};
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::Applicability;
-use rustc_hir::{BlockCheckMode, Expr, ExprKind, UnsafeSource};
+use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, UnsafeSource};
 use rustc_lint::{EarlyContext, LateContext};
 use rustc_middle::ty::TyCtxt;
 use rustc_session::Session;
@@ -739,6 +739,13 @@ pub fn str_literal_to_char_literal(
     }
 }
 
+/// Returns the span of the `unsafe` keyword of the block, including the whitespace up to the
+/// opening brace.
+pub fn unsafe_keyword_span(cx: &LateContext<'_>, block: &Block<'_>) -> Span {
+    let through_brace = cx.tcx.sess.source_map().span_through_char(block.span, '{');
+    through_brace.with_hi(through_brace.hi() - BytePos(1))
+}
+
 #[cfg(test)]
 mod test {
     use super::reindent_multiline;
@@ -4,6 +4,7 @@ use core::ops::ControlFlow;
 use rustc_ast::visit::{VisitorResult, try_visit};
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, DefKind, Res};
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::{self, Visitor, walk_block, walk_expr};
 use rustc_hir::{
     AnonConst, Arm, Block, BlockCheckMode, Body, BodyId, Expr, ExprKind, HirId, ItemId, ItemKind, LetExpr, Pat, QPath,
@@ -470,6 +471,61 @@ pub fn is_expr_unsafe<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
     v.visit_expr(e).is_break()
 }
 
+/// Checks whether the expression itself, not counting its sub-expressions, requires an unsafe
+/// context.
+pub fn is_unsafe_op<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    let typeck = cx.typeck_results();
+    match expr.kind {
+        ExprKind::InlineAsm(_) => true,
+        ExprKind::Field(base, _) => typeck.expr_ty(base).is_union(),
+        ExprKind::Unary(UnOp::Deref, ptr) => typeck.expr_ty_adjusted(ptr).is_unsafe_ptr(),
+        ExprKind::Path(ref qpath) => matches!(
+            typeck.qpath_res(qpath, expr.hir_id),
+            Res::Def(DefKind::Static { .. }, id) if cx.tcx.is_mutable_static(id) || cx.tcx.is_foreign_item(id)
+        ),
+        ExprKind::Call(callee, _) => match *typeck.expr_ty(callee).kind() {
+            ty::FnDef(id, _) => {
+                cx.tcx.fn_sig(id).skip_binder().safety() == Safety::Unsafe || is_safe_target_feature_call(cx, id, expr)
+            },
+            ty::FnPtr(_, hdr) => hdr.safety == Safety::Unsafe,
+            _ => false,
+        },
+        ExprKind::MethodCall(..) => typeck.type_dependent_def_id(expr.hir_id).is_some_and(|id| {
+            cx.tcx.fn_sig(id).skip_binder().safety() == Safety::Unsafe || is_safe_target_feature_call(cx, id, expr)
+        }),
+        _ => false,
+    }
+}
+
+/// Checks whether the call to the given safe function requires an unsafe context because the
+/// function enables target features the calling body doesn't (RFC 2396).
+pub fn is_safe_target_feature_call(cx: &LateContext<'_>, callee: DefId, call: &Expr<'_>) -> bool {
+    if cx.tcx.sess.target.is_like_wasm || cx.tcx.fn_sig(callee).skip_binder().safety() == Safety::Unsafe {
+        return false;
+    }
+    let callee_features = &cx.tcx.codegen_fn_attrs(callee).target_features;
+    if callee_features.is_empty() {
+        return false;
+    }
+    let body = cx.tcx.hir().enclosing_body_owner(call.hir_id);
+    let body_features = &cx.tcx.body_codegen_attrs(body.to_def_id()).target_features;
+    !callee_features
+        .iter()
+        .all(|feature| body_features.iter().any(|f| f.name == feature.name))
+}
+
+/// Checks if the given node contains an unsafe operation, including inside of `unsafe` blocks.
+pub fn contains_unsafe_op<'tcx>(cx: &LateContext<'tcx>, node: impl Visitable<'tcx>) -> bool {
+    for_each_expr(cx, node, |expr| {
+        if is_unsafe_op(cx, expr) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
 /// Checks if the given expression contains an unsafe block
 pub fn contains_unsafe_block<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> bool {
     struct V<'cx, 'tcx> {
@@ -0,0 +1,29 @@
+#![warn(clippy::raw_pointer_in_public_api)]
+
+pub mod sys {
+    pub fn allowed(ptr: *const u8) -> u8 {
+        unsafe { *ptr }
+    }
+
+    pub mod nested {
+        pub fn not_allowed(ptr: *const u8) -> u8 {
+            //~^ raw_pointer_in_public_api
+            unsafe { *ptr }
+        }
+    }
+}
+
+pub mod alloc {
+    pub mod raw {
+        pub fn allowed() -> *mut u8 {
+            std::ptr::null_mut()
+        }
+    }
+}
+
+pub fn not_allowed() -> *mut u8 {
+    //~^ raw_pointer_in_public_api
+    std::ptr::null_mut()
+}
+
+fn main() {}
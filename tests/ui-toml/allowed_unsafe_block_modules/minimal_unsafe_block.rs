@@ -0,0 +1,49 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+mod ffi {
+    fn exempt() {
+        unsafe {
+            let x = 1;
+            super::unsafe_fn(x);
+        }
+    }
+
+    mod nested {
+        fn not_exempt() {
+            unsafe {
+                let x = 1;
+                crate::unsafe_fn(x);
+            }
+        }
+    }
+}
+
+mod arch {
+    fn exempt() {
+        unsafe {
+            let x = 1;
+            super::unsafe_fn(x);
+        }
+    }
+
+    mod x86 {
+        fn exempt() {
+            unsafe {
+                let x = 1;
+                crate::unsafe_fn(x);
+            }
+        }
+    }
+}
+
+fn main() {
+    unsafe {
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
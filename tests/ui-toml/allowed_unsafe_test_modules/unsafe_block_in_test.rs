@@ -0,0 +1,34 @@
+//@compile-flags: --test
+#![warn(clippy::unsafe_block_in_test)]
+
+unsafe fn unsafe_fn() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod ffi {
+    #[test]
+    fn exempt() {
+        assert_eq!(unsafe { super::unsafe_fn() }, 1);
+    }
+
+    mod nested {
+        #[test]
+        fn not_exempt() {
+            assert_eq!(unsafe { crate::unsafe_fn() }, 1);
+            //~^ unsafe_block_in_test
+        }
+    }
+}
+
+#[cfg(test)]
+mod arch {
+    mod x86 {
+        #[test]
+        fn exempt() {
+            assert_eq!(unsafe { crate::unsafe_fn() }, 1);
+        }
+    }
+}
+
+fn main() {}
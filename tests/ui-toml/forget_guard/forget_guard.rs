@@ -0,0 +1,30 @@
+#![warn(clippy::forget_guard)]
+#![allow(clippy::mem_forget)]
+
+use std::mem::{self, ManuallyDrop};
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+struct Token;
+
+impl Drop for Token {
+    fn drop(&mut self) {}
+}
+
+struct Other;
+
+impl Drop for Other {
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    mem::forget(Guard);
+    //~^ forget_guard
+    let _ = ManuallyDrop::new(Token);
+    //~^ forget_guard
+    mem::forget(Other);
+}
@@ -0,0 +1,17 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+#![allow(unused_unsafe, clippy::redundant_nested_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+unsafe fn call(f: impl Fn() -> u32) -> u32 {
+    f()
+}
+
+fn main() {
+    let _ = unsafe { call(|| unsafe { unsafe_fn(0) }) };
+
+    let _ = unsafe { call(|| unsafe { call(|| unsafe { unsafe_fn(0) }) }) };
+}
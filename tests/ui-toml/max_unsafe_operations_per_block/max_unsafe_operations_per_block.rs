@@ -0,0 +1,34 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &mut 0u32 as *mut u32;
+
+    // related unsafe operations may share a block
+    unsafe {
+        unsafe_fn(0);
+        *ptr = 1;
+    }
+    let _ = unsafe { unsafe_fn(unsafe_fn(0)) };
+
+    // but not more than two of them
+    unsafe {
+        unsafe_fn(0);
+        *ptr = unsafe_fn(1);
+    }
+    let _ = unsafe { unsafe_fn(unsafe_fn(unsafe_fn(0))) };
+
+    // safe statements are still linted
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+}
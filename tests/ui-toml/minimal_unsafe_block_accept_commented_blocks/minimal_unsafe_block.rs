@@ -0,0 +1,48 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    // SAFETY: both calls are fine
+    unsafe {
+        unsafe_fn(0);
+        unsafe_fn(1);
+    }
+
+    let _ = {
+        // SAFETY: the value is computed from a safe call
+        unsafe {
+            let x = safe_fn(0);
+            unsafe_fn(x)
+        }
+    };
+
+    // SAFETY: comments on the `let` statement count, as for `undocumented_unsafe_blocks`
+    let _ = unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x)
+    };
+
+    // not a safety comment
+    unsafe {
+        unsafe_fn(0);
+        unsafe_fn(1);
+    }
+
+    // SAFETY: the comment has to immediately precede the block
+    let _y = 1;
+    unsafe {
+        unsafe_fn(0);
+        unsafe_fn(1);
+    }
+
+    // SAFETY: the tail expression is still checked
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+}
@@ -0,0 +1,50 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+#![allow(clippy::let_and_return)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &0u32 as *const u32;
+    let pair = &[0u32, 1] as *const [u32; 2];
+
+    // the `let` statement is wrapped as a whole
+    let _ = unsafe {
+        let x = ptr.read();
+        x
+    };
+
+    let _ = unsafe {
+        let [x, _] = pair.read();
+        x
+    };
+
+    unsafe {
+        let _x = unsafe_fn(0);
+    }
+
+    // the initializer isn't a single unsafe operation
+    let _ = unsafe {
+        let x = safe_fn(ptr.read());
+        x
+    };
+
+    // the tail expression isn't the bound variable
+    let _ = unsafe {
+        let x = ptr.read();
+        x + 1
+    };
+
+    // there are more statements
+    let _ = unsafe {
+        let x = ptr.read();
+        let y = safe_fn(x);
+        y
+    };
+}
@@ -0,0 +1,49 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+macro_rules! write_and_log {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write_volatile($value);
+        safe_fn($value);
+    };
+}
+
+fn main() {
+    let ptr = &mut 0u32 as *mut u32;
+
+    // every statement performs an unsafe operation
+    unsafe {
+        unsafe_fn(0);
+        *ptr = 1;
+    }
+
+    let _ = unsafe {
+        let x = unsafe_fn(0);
+        unsafe_fn(x)
+    };
+
+    // the statements of a macro call are unsafe as a whole
+    unsafe {
+        write_and_log!(ptr, 1);
+        unsafe_fn(0);
+    }
+
+    // safe statements are still linted
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+
+    let _ = unsafe {
+        unsafe_fn(0);
+        safe_fn(1)
+    };
+}
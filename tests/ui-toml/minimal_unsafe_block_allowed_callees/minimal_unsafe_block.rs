@@ -0,0 +1,51 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+mod sys {
+    pub unsafe fn open(x: u32) -> u32 {
+        x
+    }
+
+    pub unsafe fn close(_: u32) {}
+}
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &0u32 as *const u32;
+
+    // every statement calls an allowed callee
+    unsafe {
+        let fd = sys::open(0);
+        sys::close(fd);
+    }
+
+    let _ = unsafe {
+        let fd = sys::open(core::ptr::read(ptr));
+        sys::close(fd);
+        std::ptr::read(ptr)
+    };
+
+    // other unsafe operations are still linted
+    unsafe {
+        let fd = sys::open(0);
+        sys::close(unsafe_fn(fd));
+    }
+
+    unsafe {
+        let fd = sys::open(*ptr);
+        sys::close(fd);
+    }
+
+    // as are safe statements
+    unsafe {
+        let fd = safe_fn(0);
+        sys::close(fd);
+    }
+}
@@ -0,0 +1,3 @@
+//@error-in-other-file: unknown variant `sometimes`
+
+fn main() {}
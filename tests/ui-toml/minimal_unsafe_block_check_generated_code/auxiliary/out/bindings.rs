@@ -0,0 +1,7 @@
+pub fn generated() -> u32 {
+    let ptr = &0u32 as *const u32;
+    unsafe {
+        let x = 1;
+        *ptr + x
+    }
+}
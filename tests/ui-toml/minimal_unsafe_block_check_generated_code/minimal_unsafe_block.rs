@@ -0,0 +1,36 @@
+//@no-rustfix
+//@rustc-env:OUT_DIR=tests/ui-toml/minimal_unsafe_block_check_generated_code/auxiliary/out
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+// what a derive macro would expand to
+struct Wrapper(*const u32);
+
+#[automatically_derived]
+impl Clone for Wrapper {
+    fn clone(&self) -> Self {
+        unsafe {
+            let x = unsafe_fn(0);
+            Wrapper(self.0.add(x as usize))
+        }
+    }
+}
+
+// a file included from the build script output directory
+include!("auxiliary/out/bindings.rs");
+
+// a file included from elsewhere is still linted
+mod handwritten {
+    include!("auxiliary/handwritten.rs");
+}
+
+// hand-written code is still linted
+fn main() {
+    unsafe {
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
@@ -0,0 +1,32 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+macro_rules! ffi_call {
+    ($value:expr) => {
+        unsafe {
+            let x = $value;
+            unsafe_fn(x)
+        }
+    };
+}
+
+macro_rules! call_with {
+    ($body:expr) => {
+        $body
+    };
+}
+
+fn main() {
+    // blocks produced by local macros aren't checked
+    let _ = ffi_call!(1);
+
+    // blocks passed to macros are written by hand
+    let _ = call_with!(unsafe {
+        let x = 1;
+        unsafe_fn(x)
+    });
+}
@@ -0,0 +1,32 @@
+//@no-rustfix
+//@compile-flags: --test
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn not_a_test() {
+    unsafe {
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
+
+#[test]
+fn test_fn() {
+    unsafe {
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        unsafe {
+            let x = 1;
+            super::unsafe_fn(x);
+        }
+    }
+}
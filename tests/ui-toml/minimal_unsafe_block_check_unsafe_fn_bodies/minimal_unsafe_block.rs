@@ -0,0 +1,42 @@
+//@no-rustfix
+//@edition:2021
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+unsafe fn implicit(ptr: *const u32) -> u32 {
+    let x = safe_fn(1);
+    let y = *ptr;
+    unsafe_fn(x + y)
+}
+
+unsafe fn safe_tail() -> u32 {
+    unsafe_fn(1);
+    safe_fn(2)
+}
+
+// every statement performs an unsafe operation
+unsafe fn only_unsafe(ptr: *const u32) -> u32 {
+    let x = *ptr;
+    unsafe_fn(x)
+}
+
+// the unsafe operations are already in explicit blocks
+unsafe fn explicit() -> u32 {
+    let x = safe_fn(1);
+    unsafe { unsafe_fn(x) }
+}
+
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn denied() -> u32 {
+    let x = safe_fn(1);
+    unsafe { unsafe_fn(x) }
+}
+
+fn main() {}
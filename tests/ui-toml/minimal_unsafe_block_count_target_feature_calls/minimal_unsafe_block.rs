@@ -0,0 +1,29 @@
+//@no-rustfix
+//@only-target: x86_64
+#![feature(target_feature_11)]
+#![warn(clippy::minimal_unsafe_block)]
+
+#[target_feature(enable = "avx2")]
+fn simd(x: u32) -> u32 {
+    x
+}
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    // calls to `simd` aren't counted
+    unsafe {
+        let x = simd(1);
+        let y = simd(x);
+        unsafe_fn(y);
+    }
+
+    // other unsafe operations still are
+    unsafe {
+        let x = simd(1);
+        let y = unsafe_fn(x);
+        unsafe_fn(y);
+    }
+}
@@ -0,0 +1,37 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let reg = &mut 0u32 as *mut u32;
+
+    // pointer dereferences don't count
+    unsafe {
+        let value = *reg;
+        *reg = value | 1;
+    }
+
+    unsafe {
+        let value = *reg;
+        *reg = unsafe_fn(value);
+    }
+
+    // but are still kept apart from safe code
+    unsafe {
+        let value = safe_fn(1);
+        *reg = value;
+    }
+
+    // calls still count
+    unsafe {
+        let value = unsafe_fn(*reg);
+        *reg = unsafe_fn(value);
+    }
+}
@@ -0,0 +1,44 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+// each unsafe operation is expected to get a block of its own
+#[deny(unsafe_op_in_unsafe_fn)]
+unsafe fn denied(ptr: *const u32) -> u32 {
+    let x = unsafe { unsafe_fn(*ptr) };
+    let y = unsafe { *ptr };
+    let z = unsafe {
+        let a = 1;
+        unsafe_fn(a)
+    };
+    let f = || unsafe { unsafe_fn(0) + unsafe_fn(1) };
+    x + y + z + f()
+}
+
+// the blocks are redundant anyway
+#[allow(unsafe_op_in_unsafe_fn, unused_unsafe)]
+unsafe fn allowed(ptr: *const u32) -> u32 {
+    unsafe {
+        let a = safe_fn(1);
+        unsafe_fn(*ptr) + a
+    }
+}
+
+// safe functions are unaffected
+fn safe(ptr: *const u32) -> u32 {
+    let x = unsafe { unsafe_fn(*ptr) };
+    let y = unsafe {
+        let a = 1;
+        unsafe_fn(a)
+    };
+    x + y
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn use_it(_: u32) {}
+
+fn stmts(ptr: *mut u32) {
+    unsafe {
+        let x = safe_fn(0);
+        ptr.write(x);
+        let y = safe_fn(x);
+        ptr.write(unsafe_fn(y));
+        use_it(y);
+    }
+    use_it(1);
+}
+
+fn tail() {
+    let x = unsafe { safe_fn(unsafe_fn(0)) };
+    use_it(x);
+}
+
+fn main() {}
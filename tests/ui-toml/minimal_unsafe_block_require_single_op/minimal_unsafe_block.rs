@@ -0,0 +1,23 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &mut 0u32 as *mut u32;
+
+    // one unsafe operation per block is fine
+    let x = unsafe { unsafe_fn(0) };
+    unsafe { *ptr = x };
+
+    unsafe {
+        unsafe_fn(0);
+        *ptr = 1;
+    }
+
+    // blocks that can't be narrowed any further are still linted
+    let _ = unsafe { unsafe_fn(unsafe_fn(0)) };
+    unsafe { *ptr = unsafe_fn(1) };
+}
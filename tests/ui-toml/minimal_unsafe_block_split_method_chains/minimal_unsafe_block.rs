@@ -0,0 +1,43 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+struct Reg(u32);
+
+impl Reg {
+    unsafe fn read(&self) -> u32 {
+        self.0
+    }
+
+    fn get(&self) -> &Self {
+        self
+    }
+}
+
+fn main() {
+    let ptr = &0u32 as *const u32;
+    let reg = Reg(0);
+
+    // one block around a chain with an unsafe call is accepted
+    let _ = unsafe { ptr.add(0).read().to_string() };
+    let _ = unsafe { reg.get().read().count_ones() };
+    let _ = unsafe { unsafe_fn(0).to_string() };
+    unsafe {
+        ptr.read().to_string();
+    }
+
+    // other safe code is still linted
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+    let _ = unsafe { (*ptr).to_string() };
+    unsafe {
+        let x = safe_fn(0);
+        ptr.add(x as usize).read().to_string();
+    }
+}
@@ -0,0 +1,34 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &mut 0u32 as *mut u32;
+    let x = 1;
+    let y = 1u8;
+
+    // the operands are computed by safe code
+    let _ = unsafe { unsafe_fn(safe_fn(x)) };
+    let _ = unsafe { unsafe_fn(x + 1) };
+    unsafe { *ptr = safe_fn(x) };
+    unsafe {
+        ptr.write(safe_fn(x));
+    }
+
+    // the operands are trivial
+    let _ = unsafe { unsafe_fn(x) };
+    let _ = unsafe { unsafe_fn(1) };
+    let _ = unsafe { ptr.read() };
+    unsafe { *ptr = y as u32 };
+
+    // the operands are unsafe themselves
+    let _ = unsafe { unsafe_fn(unsafe_fn(x)) };
+    let _ = unsafe { ptr.add(safe_fn(x) as usize).read() };
+}
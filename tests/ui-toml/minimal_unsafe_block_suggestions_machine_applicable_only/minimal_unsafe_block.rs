@@ -0,0 +1,20 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let ptr = &0u32 as *const u32;
+
+    // machine applicable
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+
+    // maybe incorrect
+    let _ = unsafe { safe_fn(*ptr) };
+}
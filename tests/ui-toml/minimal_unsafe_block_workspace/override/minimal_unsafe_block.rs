@@ -0,0 +1,27 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+// The options not set by the crate have their default values, not the ones of the workspace.
+fn main() {
+    let ptr = &0u32 as *const u32;
+
+    unsafe {
+        unsafe_fn(0);
+        ptr.read();
+    }
+
+    let _ = unsafe { ptr.read().to_string() };
+
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::transmute_with_safe_alternative)]
+#![allow(clippy::missing_transmute_annotations)]
+
+use std::mem::transmute;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Header {
+    version: u8,
+    kind: u8,
+    len: u16,
+}
+
+impl Header {
+    pub fn from_bytes(bytes: [u8; 4]) -> Self {
+        // The replacement itself isn't linted
+        unsafe { transmute(bytes) }
+    }
+
+    pub fn to_bytes(self) -> [u8; 4] {
+        assert_eq!(self.version, 1);
+        unsafe { transmute(self) }
+    }
+}
+
+#[repr(transparent)]
+pub struct Wrapper<T>(T);
+
+impl Wrapper<u32> {
+    pub fn new(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+fn main() {
+    let bytes = [1u8, 0, 4, 0];
+    let header: Header = unsafe { transmute(bytes) };
+    //~^ transmute_with_safe_alternative
+    let _: [u8; 4] = unsafe { transmute(header) };
+    //~^ transmute_with_safe_alternative
+    let _: Wrapper<u32> = unsafe { transmute(1u32) };
+    //~^ transmute_with_safe_alternative
+
+    // The other transmute lints still apply
+    let _: char = unsafe { transmute(0x61u32) };
+    //~^ transmute_with_safe_alternative
+    //~| transmute_int_to_char
+
+    // Not configured
+    let _: u32 = unsafe { transmute(bytes) };
+    let _: Header = unsafe { transmute(1u32) };
+}
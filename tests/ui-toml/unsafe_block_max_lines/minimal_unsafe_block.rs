@@ -0,0 +1,48 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    // SAFETY: upheld by all calls
+    unsafe {
+        unsafe_fn(0);
+        unsafe_fn(1);
+    }
+
+    // SAFETY: upheld by all calls
+    unsafe {
+        unsafe_fn(0);
+        unsafe_fn(1);
+        unsafe_fn(2);
+    }
+
+    let _ = unsafe { unsafe_fn(0) };
+
+    let _ = unsafe {
+        unsafe_fn(
+            // a comment
+            0,
+        )
+    };
+}
+
+// blocks without unsafe operations are left to `unused_unsafe`
+#[allow(clippy::let_unit_value)]
+fn no_unsafe_ops() {
+    #[expect(unused_unsafe)]
+    let _ = unsafe {
+        let x = 1;
+        let y = x + 1;
+        let _ = y;
+    };
+
+    #[allow(unused_unsafe)]
+    let _ = unsafe {
+        let x = 1;
+        let y = x + 1;
+        let _ = y;
+    };
+}
@@ -0,0 +1,60 @@
+#![warn(clippy::adjacent_unsafe_blocks)]
+#![allow(clippy::missing_safety_doc)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn stmts(ptr: *mut u32) {
+    //~v adjacent_unsafe_blocks
+    unsafe { ptr.write(1) };
+    unsafe {
+        ptr.write(2);
+    }
+    unsafe { unsafe_fn(3) };
+    safe_fn(4);
+    unsafe { ptr.write(5) };
+}
+
+fn tail(ptr: *mut u32) -> u32 {
+    //~v adjacent_unsafe_blocks
+    unsafe {
+        ptr.write(1);
+    }
+    unsafe { ptr.read() }
+}
+
+fn bindings(ptr: *mut u32) {
+    // the binding would live longer after merging
+    unsafe {
+        let x = unsafe_fn(1);
+        ptr.write(x);
+    }
+    unsafe { ptr.write(2) };
+
+    //~v adjacent_unsafe_blocks
+    unsafe { ptr.write(1) };
+    unsafe {
+        let x = unsafe_fn(2);
+        ptr.write(x);
+    }
+}
+
+fn comments(ptr: *mut u32) {
+    // SAFETY: blocks with comments between them are documented separately
+    unsafe { ptr.write(1) };
+    // SAFETY: ..
+    unsafe { ptr.write(2) };
+}
+
+fn single(ptr: *mut u32) {
+    unsafe { ptr.write(1) };
+    let x = 2;
+    unsafe { ptr.write(x) };
+}
+
+fn main() {}
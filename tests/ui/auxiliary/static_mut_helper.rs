@@ -0,0 +1,2 @@
+pub static mut EXTERNAL_COUNTER: u32 = 0;
+pub static EXTERNAL_IMMUTABLE: u32 = 0;
@@ -0,0 +1,43 @@
+#![warn(clippy::await_in_unsafe_block)]
+#![allow(clippy::redundant_nested_unsafe_block, clippy::redundant_async_block, unused)]
+
+async fn fetch() -> u32 {
+    0
+}
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+async fn awaits(ptr: *mut u32) {
+    unsafe {
+        //~^ await_in_unsafe_block
+        let value = fetch().await;
+        ptr.write(value);
+    }
+
+    let x = unsafe { unsafe_fn(fetch().await) + fetch().await };
+    //~^ await_in_unsafe_block
+}
+
+async fn nested(ptr: *mut u32) {
+    // only the innermost block is linted
+    unsafe {
+        ptr.write(1);
+        unsafe {
+            //~^ await_in_unsafe_block
+            ptr.write(fetch().await);
+        }
+    }
+}
+
+async fn not_suspended(ptr: *mut u32) {
+    let value = fetch().await;
+    unsafe { ptr.write(value) };
+
+    // the `async` block is awaited on its own
+    let fut = unsafe { async move { ptr.write(fetch().await) } };
+    fut.await;
+}
+
+fn main() {}
@@ -28,6 +28,11 @@ fn main() {
     (&mut 1u8 as *mut u8).cast::<u16>();
     //~^ ERROR: casting from `*mut u8` to a more-strictly-aligned pointer (`*mut u16`) (1
 
+    // cast of the pointer to a slice
+    let bytes = [0u8; 8];
+    bytes.as_ptr() as *const u64;
+    //~^ ERROR: casting from `*const u8` to a more-strictly-aligned pointer (`*const u64`)
+
     /* These should be ok */
 
     // not a pointer type
@@ -0,0 +1,67 @@
+#![warn(clippy::unnecessary_unwrap_unchecked, clippy::panicking_unwrap)]
+#![allow(
+    clippy::unnecessary_unwrap,
+    clippy::if_same_then_else,
+    clippy::unnecessary_literal_unwrap
+)]
+
+fn main() {
+    let x = Some(1u32);
+    if x.is_some() {
+        let _ = unsafe { x.unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+        let _ = unsafe { x.map(|v| v + 1).unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+        let _ = unsafe { x.as_ref().unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+    }
+    if x.is_none() {
+        // nothing to unwrap
+    } else {
+        let _ = unsafe { x.unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+    }
+    let b = true;
+    if x.is_some() && b {
+        let _ = unsafe { x.unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+    }
+
+    let r: Result<u32, u8> = Ok(1);
+    if r.is_ok() {
+        let _ = unsafe { r.unwrap_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+    } else {
+        let _ = unsafe { r.unwrap_err_unchecked() };
+        //~^ unnecessary_unwrap_unchecked
+    }
+
+    // always undefined behavior
+    if x.is_none() {
+        let _ = unsafe { x.unwrap_unchecked() };
+        //~^ panicking_unwrap
+        let _ = unsafe { x.map(|v| v + 1).unwrap_unchecked() };
+        //~^ panicking_unwrap
+    }
+    if r.is_ok() {
+        let _ = unsafe { r.unwrap_err_unchecked() };
+        //~^ panicking_unwrap
+    } else {
+        let _ = unsafe { r.unwrap_unchecked() };
+        //~^ panicking_unwrap
+    }
+
+    // no check
+    let _ = unsafe { x.unwrap_unchecked() };
+
+    let mut y = Some(1);
+    if y.is_some() {
+        y = None;
+        let _ = unsafe { y.unwrap_unchecked() };
+    }
+
+    // not a variant preserving adapter
+    if x.is_some() {
+        let _ = unsafe { x.and_then(|v| v.checked_sub(1)).unwrap_unchecked() };
+    }
+}
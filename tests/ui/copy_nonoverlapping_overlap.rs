@@ -0,0 +1,46 @@
+#![warn(clippy::copy_nonoverlapping_overlap)]
+
+use std::ptr::{self, copy_nonoverlapping};
+
+fn overlapping(buf: &mut [u32], len: usize, i: usize) {
+    let ptr = buf.as_mut_ptr();
+    unsafe {
+        copy_nonoverlapping(ptr, ptr.add(1), 4);
+        //~^ copy_nonoverlapping_overlap
+        ptr::copy_nonoverlapping(buf.as_ptr().add(2), buf.as_mut_ptr(), 3);
+        //~^ copy_nonoverlapping_overlap
+        copy_nonoverlapping(ptr.add(i), ptr.add(i + 2), 4);
+        //~^ copy_nonoverlapping_overlap
+        copy_nonoverlapping(ptr.add(1), ptr, len - 1);
+        //~^ copy_nonoverlapping_overlap
+        copy_nonoverlapping(ptr, ptr, len);
+        //~^ copy_nonoverlapping_overlap
+
+        let src = ptr.add(3);
+        src.copy_to_nonoverlapping(ptr.add(5), 8);
+        //~^ copy_nonoverlapping_overlap
+        ptr.copy_from_nonoverlapping(ptr.add(1).cast_const(), 2);
+        //~^ copy_nonoverlapping_overlap
+    }
+}
+
+fn not_overlapping(buf: &mut [u32], other: &mut [u32], len: usize, i: usize) {
+    let ptr = buf.as_mut_ptr();
+    unsafe {
+        copy_nonoverlapping(ptr, ptr.add(4), 4);
+        copy_nonoverlapping(ptr.add(i + 4), ptr.add(i), 3);
+        copy_nonoverlapping(ptr, other.as_mut_ptr(), len);
+        ptr.copy_from_nonoverlapping(ptr.add(1), 1);
+        ptr::copy(ptr, ptr.add(1), 4);
+
+        // Unknown distance
+        copy_nonoverlapping(ptr, ptr.add(len), len);
+        copy_nonoverlapping(ptr.add(i), ptr.add(len), 2);
+
+        // Offsets in other units
+        let bytes = ptr as *mut u8;
+        copy_nonoverlapping(bytes, ptr.add(1) as *mut u8, 4);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::forget_guard)]
+#![allow(clippy::mem_forget)]
+
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::sync::{Mutex, RwLock};
+
+fn main() {
+    let mutex = Mutex::new(0);
+    let guard = mutex.lock().unwrap();
+    mem::forget(guard);
+    //~^ forget_guard
+
+    let lock = RwLock::new(0);
+    let read = lock.read().unwrap();
+    mem::forget(read);
+    //~^ forget_guard
+    let write = lock.write().unwrap();
+    let _ = ManuallyDrop::new(write);
+    //~^ forget_guard
+
+    let cell = RefCell::new(0);
+    std::mem::forget(cell.borrow_mut());
+    //~^ forget_guard
+
+    // Not guards
+    mem::forget(String::new());
+    let _ = ManuallyDrop::new(vec![1]);
+    let guard = mutex.lock().unwrap();
+    drop(guard);
+}
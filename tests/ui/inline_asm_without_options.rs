@@ -0,0 +1,45 @@
+//@only-target: x86_64
+#![warn(clippy::inline_asm_without_options)]
+
+use std::arch::{asm, global_asm};
+
+unsafe fn asm_without_options(x: u64) -> u64 {
+    let y: u64;
+    asm!("lea {}, [{} + 1]", lateout(reg) y, in(reg) x);
+    //~^ inline_asm_without_options
+    asm!("nop", options());
+    //~^ inline_asm_without_options
+    asm!("nop", options(att_syntax));
+    //~^ inline_asm_without_options
+    y
+}
+
+unsafe fn asm_with_options(x: u64) -> u64 {
+    let y: u64;
+    asm!("lea {}, [{} + 1]", lateout(reg) y, in(reg) x, options(pure, nomem, nostack));
+    asm!("mov {}, [{}]", lateout(reg) _, in(reg) &x, options(readonly));
+    asm!("nop", options(nostack));
+    asm!("nop", options(preserves_flags, att_syntax));
+    y
+}
+
+unsafe fn asm_noreturn() -> ! {
+    asm!("ud2", options(noreturn));
+}
+
+global_asm!("");
+
+macro_rules! asm_in_macro {
+    () => {
+        asm!("nop")
+        //~^ inline_asm_without_options
+    };
+}
+
+fn main() {
+    unsafe {
+        asm_without_options(1);
+        asm_with_options(1);
+        asm_in_macro!();
+    }
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::invalid_unchecked_constructor)]
+
+use std::num::{NonZero, NonZeroU32, NonZeroUsize};
+
+const ZERO: usize = 0;
+const ZERO_I8: i8 = 0;
+const SURROGATE: u32 = 0xDFFF;
+
+fn main() {
+    let _ = unsafe { NonZeroU32::new_unchecked(0) };
+    //~^ invalid_unchecked_constructor
+    let _ = unsafe { NonZeroUsize::new_unchecked(ZERO) };
+    //~^ invalid_unchecked_constructor
+    let _ = unsafe { NonZero::<i8>::new_unchecked(ZERO_I8) };
+    //~^ invalid_unchecked_constructor
+    let _ = unsafe { char::from_u32_unchecked(0xD800) };
+    //~^ invalid_unchecked_constructor
+    let _ = unsafe { std::char::from_u32_unchecked(SURROGATE) };
+    //~^ invalid_unchecked_constructor
+    let _ = unsafe { char::from_u32_unchecked(0x110000) };
+    //~^ invalid_unchecked_constructor
+
+    // Valid or unknown values
+    let _ = unsafe { NonZeroU32::new_unchecked(1) };
+    let _ = unsafe { char::from_u32_unchecked(0x61) };
+    let n = std::env::args().count() as u32;
+    let _ = unsafe { NonZeroU32::new_unchecked(n) };
+    let _ = unsafe { char::from_u32_unchecked(n) };
+}
@@ -0,0 +1,565 @@
+//@aux-build:proc_macros.rs
+#![feature(let_chains)]
+#![warn(clippy::minimal_unsafe_block)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::let_unit_value,
+    clippy::never_loop,
+    clippy::needless_return,
+    clippy::redundant_nested_unsafe_block,
+    unused
+)]
+
+extern crate proc_macros;
+use proc_macros::external;
+use std::sync::Mutex;
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+struct S;
+
+impl S {
+    unsafe fn unsafe_m(&self) -> &Self {
+        self
+    }
+    fn safe_m(&self) -> u32 {
+        0
+    }
+}
+
+static mut STATIC: u32 = 0;
+
+fn minimal(ptr: *mut u32, pair: *const (u32, u32)) {
+    let _ = unsafe { unsafe_fn(0) };
+    let _ = unsafe { unsafe_fn(safe_fn(0)) };
+    let _ = unsafe { *ptr };
+    let _ = unsafe { &*ptr };
+    let _ = unsafe { &(*pair).0 };
+    unsafe { *ptr = safe_fn(1) };
+    let _ = unsafe { STATIC };
+    let _ = unsafe { S.unsafe_m() };
+}
+
+fn statements(ptr: *mut u32) {
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+
+    unsafe {
+        let x = 1;
+        *ptr = x;
+        safe_fn(x);
+        safe_fn(x);
+    }
+
+    unsafe {
+        unsafe_fn(0);
+    }
+
+    // a single unsafe operation without a value is fine either way
+    unsafe {
+        ptr.write(0);
+    }
+
+    let _ = unsafe {
+        let x = 1;
+        unsafe_fn(x)
+    };
+}
+
+fn use_it(_: u32) {}
+
+fn let_init() {
+    unsafe {
+        let y = unsafe_fn(0);
+        use_it(y);
+    }
+
+    let _ = unsafe {
+        let y: u32 = unsafe_fn(0) + 1;
+        safe_fn(y)
+    };
+
+    // the unsafe operations are spread over several statements
+    unsafe {
+        let y = unsafe_fn(0);
+        use_it(unsafe_fn(y));
+    }
+}
+
+fn let_else(ptr: *const u32) {
+    unsafe {
+        let Some(x) = ptr.as_ref() else { return };
+        use_it(*x);
+    }
+}
+
+fn items() {
+    // nested items are not statements that need to be moved out
+    let _ = unsafe {
+        fn helper() {}
+        struct Local;
+        unsafe_fn(0)
+    };
+
+    unsafe {
+        fn helper() {}
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
+
+fn split(ptr: *mut u32) {
+    unsafe {
+        unsafe_fn(0);
+        *ptr = 1;
+        STATIC += 1;
+    }
+
+    let _ = unsafe {
+        let x = unsafe_fn(0);
+        unsafe_fn(x)
+    };
+
+    // more than one unsafe operation in a statement
+    unsafe {
+        unsafe_fn(0);
+        *ptr = unsafe_fn(1);
+    }
+}
+
+fn statements_and_tail() {
+    let _ = unsafe {
+        let x = 1;
+        safe_fn(unsafe_fn(x))
+    };
+
+    let _ = unsafe {
+        unsafe_fn(0);
+        (unsafe_fn(1), 2)
+    };
+}
+
+#[allow(unused_unsafe)]
+fn nested() {
+    // blocks in nested items are unrelated to the outer block
+    unsafe {
+        fn inner() {
+            unsafe {
+                let x = 1;
+                unsafe_fn(x);
+            }
+        }
+        let x = 1;
+        unsafe_fn(x);
+    }
+}
+
+#[allow(unused_unsafe)]
+fn redundant_nesting() {
+    let _ = unsafe { unsafe { unsafe_fn(0) } };
+}
+
+fn trailing(ptr: *mut u32) {
+    unsafe {
+        ptr.write(1);
+        use_it(2);
+        use_it(3);
+    }
+    use_it(4);
+}
+
+fn nested_blocks(ptr: *mut u32) {
+    unsafe {
+        {
+            let x = safe_fn(0);
+            unsafe_fn(x);
+        }
+        use_it(1);
+    }
+
+    unsafe {
+        use_it(0);
+        {
+            {
+                use_it(1);
+                *ptr = 1;
+            }
+            use_it(2);
+        }
+    }
+}
+
+fn summary(ptr: *mut u32) {
+    unsafe {
+        let x = safe_fn(0);
+        let y = safe_fn(x);
+        std::ptr::write(ptr, y);
+        use_it(x);
+        use_it(y);
+    }
+}
+
+fn discard() {
+    unsafe {
+        let _ = unsafe_fn(0);
+    }
+
+    unsafe {
+        let _: u32 = unsafe_fn(0);
+    };
+
+    unsafe {
+        use_it(1);
+        let _ = unsafe_fn(0);
+    }
+}
+
+fn whole_body(ptr: *mut u32) {
+    unsafe {
+        let x = safe_fn(0);
+        *ptr = x;
+    }
+}
+
+impl S {
+    fn whole_body_method(&self) {
+        unsafe {
+            self.unsafe_m();
+            self.safe_m();
+        };
+    }
+}
+
+fn whole_body_tail() -> u32 {
+    unsafe { safe_fn(unsafe_fn(0)) }
+}
+
+fn whole_body_ops(ptr: *mut u32) -> u32 {
+    // each unsafe operation gets an `unsafe` block of its own
+    unsafe {
+        let x = safe_fn(unsafe_fn(0) + unsafe_fn(1));
+        *ptr = x;
+        let Some(y) = ptr.as_ref() else { return 0 };
+        S.unsafe_m().unsafe_m().safe_m() + *y
+    }
+}
+
+fn whole_body_large(ptr: *mut u32) {
+    // SAFETY: the suggestion for larger bodies is only a starting point
+    unsafe {
+        let x = safe_fn(0);
+        let y = safe_fn(x);
+        use_it(x);
+        use_it(y);
+        unsafe_fn(x);
+        use_it(x);
+        use_it(y);
+        ptr.write(y);
+        use_it(x);
+        use_it(y);
+        use_it(x + y);
+    }
+}
+
+// minimal blocks are fine, even if they make up the whole body
+fn whole_body_minimal() -> u32 {
+    unsafe { unsafe_fn(0) }
+}
+
+fn drop_order(m: &Mutex<*mut u32>) {
+    // moving the guard out of the block would hold the lock for longer
+    unsafe {
+        let mut guard = m.lock().unwrap();
+        **guard = 1;
+    }
+
+    // the guard is a temporary of the tail expression
+    let _ = unsafe { safe_fn(**m.lock().unwrap()) };
+
+    // dropping a `u32` has no side effects
+    unsafe {
+        let x = safe_fn(0);
+        **m.lock().unwrap() = x;
+    }
+}
+
+fn tails(ptr: *mut u32, pair: *const (u32, u32)) {
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+    let _ = unsafe { S.unsafe_m().safe_m() };
+    let _ = unsafe { [unsafe_fn(0), 1, 2] };
+    let _ = unsafe { (unsafe_fn(0), 1) };
+    let _ = unsafe { if unsafe_fn(0) == 0 { 1 } else { 2 } };
+    let _ = unsafe { *ptr + 1 };
+    let _ = unsafe { safe_fn((*pair).0) };
+    // a method call may directly follow the `unsafe` block in statement position
+    unsafe { S.unsafe_m().safe_m() };
+}
+
+fn parentheses(ptr: *const u32) -> Option<u32> {
+    // only a method call, a field access or `?` may directly follow a block starting a statement
+    unsafe {
+        use_it(0);
+        unsafe_fn(1) as u8;
+    }
+    unsafe {
+        use_it(0);
+        unsafe_fn(1).count_ones();
+    }
+    unsafe { ptr.as_ref()?.count_ones() };
+    unsafe { unsafe_fn(0) + 1 };
+
+    // the same goes for match arms
+    let _ = match 0 {
+        0 => unsafe { unsafe_fn(0) as u8 },
+        _ => unsafe { unsafe_fn(1).count_ones() as u8 },
+    };
+
+    let _ = unsafe { unsafe_fn(0) as u8 };
+    Some(0)
+}
+
+fn elements() {
+    let _ = unsafe { [unsafe_fn(0), 1, unsafe_fn(2)] };
+    let _ = unsafe { (unsafe_fn(0), S.unsafe_m().safe_m(), 2) };
+    let _ = unsafe {
+        [
+            unsafe_fn(0), // the trailing comma is kept
+            safe_fn(1),
+            unsafe_fn(2),
+        ]
+    };
+}
+
+fn conditions(ptr: *const Option<u32>) {
+    let _ = unsafe {
+        if unsafe_fn(0) == 0 && S.unsafe_m().safe_m() == 0 {
+            1
+        } else {
+            2
+        }
+    };
+    let _ = unsafe {
+        if let Some(x) = Some(unsafe_fn(0))
+            && unsafe_fn(x) > 0
+        {
+            1
+        } else {
+            2
+        }
+    };
+    let _ = unsafe {
+        if let Some(x) = *ptr
+            && unsafe_fn(x) > 0
+        {
+            1
+        } else {
+            2
+        }
+    };
+}
+
+fn chains() {
+    // the chain is split at the last unsafe call
+    let _ = unsafe { S.unsafe_m().unsafe_m().safe_m() };
+
+    unsafe {
+        use_it(0);
+        let _ = S.unsafe_m().unsafe_m().safe_m();
+    }
+
+    let v = [1u32, 2];
+    let _ = unsafe { v.as_ptr().add(1).read().to_string().len() };
+}
+
+fn repeated(ptr: *mut u32) {
+    // the same unsafe function is called repeatedly
+    unsafe {
+        let x = safe_fn(0);
+        ptr.write(x);
+        ptr.write(x + 1);
+        ptr.write(x + 2);
+    }
+
+    let _ = unsafe { [unsafe_fn(0), unsafe_fn(1), unsafe_fn(2)] };
+}
+
+fn comments() {
+    let _ = unsafe {
+        // the comment would get lost
+        safe_fn(unsafe_fn(0))
+    };
+}
+
+fn safety_comments(ptr: *mut u32) {
+    // SAFETY: the comment is moved to the new block
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+
+    // SAFETY: the comment is duplicated
+    // for each new block
+    unsafe {
+        unsafe_fn(0);
+        use_it(1);
+        *ptr = 2;
+    }
+
+    // SAFETY: the new block stays on the line of the comment
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+}
+
+fn reindent(ptr: *mut u32) {
+    // the literal can't be reindented
+    unsafe {
+        use_it(0);
+        ptr.write(
+            "multi
+line"
+                .len() as u32,
+        );
+    }
+
+    unsafe {
+        use_it(0);
+        ptr.write(match safe_fn(1) {
+            0 => 1,
+            _ => 2,
+        });
+    }
+
+    let _ = unsafe {
+        safe_fn(match unsafe_fn(0) {
+            0 => 1,
+            _ => 2,
+        })
+    };
+}
+
+fn control_flow() {
+    // control flow that doesn't leave the block is fine
+    unsafe {
+        let x = loop {
+            break 1;
+        };
+        let _ = unsafe_fn(x);
+        let _ = || return;
+    }
+}
+
+#[allow(unused_unsafe)]
+fn no_unsafe_ops() {
+    let _ = unsafe { safe_fn(0) };
+    unsafe {
+        safe_fn(0);
+    }
+}
+
+macro_rules! write_and_log {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write_volatile($value);
+        use_it($value);
+    };
+}
+
+fn local_macros(ptr: *mut u32) {
+    // the statements of a single macro call can't be narrowed any further
+    unsafe {
+        write_and_log!(ptr, 1);
+    }
+}
+
+macro_rules! write_reg {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write_volatile($value)
+    };
+}
+
+macro_rules! log {
+    ($value:expr) => {
+        use_it($value)
+    };
+}
+
+fn hoisting(ptr: *mut u32) -> Option<u32> {
+    // the control flow is moved along with the statements
+    unsafe {
+        let x = safe_fn(0);
+        if x == 0 {
+            return None;
+        }
+        unsafe_fn(x);
+    }
+
+    for i in 0..2 {
+        unsafe {
+            let x = safe_fn(i);
+            if x == 0 {
+                continue;
+            }
+            unsafe_fn(x);
+        }
+    }
+
+    // the statements of a macro call can only be moved as a whole
+    unsafe {
+        log!(1);
+        write_reg!(ptr, 2);
+        log!(3);
+    }
+
+    // SAFETY: the comment stays above the `unsafe` block
+    unsafe {
+        log!(1);
+        write_reg!(ptr, 2);
+    }
+
+    let _ = unsafe {
+        let x = safe_fn(0);
+        write_reg!(ptr, x)
+    };
+
+    Some(0)
+}
+
+macro_rules! read_offset {
+    ($ptr:expr) => {
+        unsafe {
+            let offset = 1;
+            $ptr.read() + offset
+        }
+    };
+}
+
+fn macro_contexts(ptr: *const u32) {
+    // the block is part of the macro body
+    let _ = read_offset!(ptr);
+
+    // the unsafe operation is passed to a macro call
+    unsafe {
+        use_it(0);
+        use_it(vec![unsafe_fn(0)].len() as u32);
+    }
+
+    let _ = unsafe { safe_fn(vec![unsafe_fn(0)][0]) };
+}
+
+fn external_macro() {
+    external! {
+        unsafe {
+            let x = 1;
+            unsafe_fn(x);
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn statements() {
+    #[expect(clippy::minimal_unsafe_block)]
+    let _ = unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x)
+    };
+
+    #[expect(clippy::minimal_unsafe_block)]
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+
+    #[allow(clippy::minimal_unsafe_block)]
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+}
+
+fn closures() {
+    #[expect(clippy::minimal_unsafe_block)]
+    let f = || unsafe { safe_fn(unsafe_fn(0)) };
+    f();
+}
+
+// the attribute only applies to its own statement
+fn neighbors() {
+    let _ = unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x)
+    };
+    #[allow(clippy::minimal_unsafe_block)]
+    let _ = unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x)
+    };
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+
+mod bindings {
+    extern "C" {
+        pub fn ctx_new() -> *mut u8;
+        pub fn ctx_set(ctx: *mut u8, value: i32) -> i32;
+        pub fn ctx_free(ctx: *mut u8);
+    }
+}
+
+unsafe fn unsafe_fn(x: i32) -> i32 {
+    x
+}
+
+fn safe_fn(x: i32) -> i32 {
+    x
+}
+
+fn ffi_stmts() {
+    unsafe {
+        let ctx = bindings::ctx_new();
+        let value = safe_fn(1);
+        bindings::ctx_set(ctx, value);
+        bindings::ctx_free(ctx);
+    }
+}
+
+fn ffi_tail(ctx: *mut u8) -> i32 {
+    let status = unsafe { safe_fn(bindings::ctx_set(ctx, 1)) };
+    status + 1
+}
+
+fn mixed(ctx: *mut u8) {
+    // not all the unsafe operations are FFI calls
+    unsafe {
+        let value = safe_fn(1);
+        bindings::ctx_set(ctx, unsafe_fn(value));
+    }
+}
+
+fn main() {}
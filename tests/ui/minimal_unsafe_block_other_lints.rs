@@ -0,0 +1,77 @@
+// The `.fixed` file is checked with the lints below enabled, so applying the suggestions must not
+// trigger any of them.
+#![warn(clippy::minimal_unsafe_block)]
+#![warn(
+    unused_unsafe,
+    unused_parens,
+    unused_braces,
+    clippy::redundant_closure_call,
+    clippy::semicolon_if_nothing_returned,
+    clippy::blocks_in_conditions,
+    clippy::unnecessary_operation,
+    clippy::let_and_return,
+    clippy::needless_return
+)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn use_it(_: u32) {}
+
+macro_rules! read_at {
+    ($ptr:expr, $offset:expr) => {
+        $ptr.add($offset as usize).read()
+    };
+}
+
+fn semicolons(ptr: *mut u32) {
+    unsafe {
+        use_it(0);
+        ptr.write(match safe_fn(1) {
+            0 => 1,
+            _ => 2,
+        });
+    }
+
+    unsafe {
+        use_it(0);
+        unsafe_fn(1);
+    }
+}
+
+fn closures() -> u32 {
+    let f = || unsafe { safe_fn(unsafe_fn(0)) };
+    let g = |x| unsafe {
+        let y = safe_fn(x);
+        unsafe_fn(y)
+    };
+    f() + g(1)
+}
+
+fn conditions() -> u32 {
+    let x = unsafe { if unsafe_fn(0) == 0 { 1 } else { 2 } };
+    x + 1
+}
+
+fn macro_tail(ptr: *const u32) -> u32 {
+    let value = unsafe {
+        let x = safe_fn(0);
+        read_at!(ptr, x)
+    };
+    value + 1
+}
+
+unsafe fn in_unsafe_fn(ptr: *mut u32) {
+    unsafe {
+        let x = safe_fn(0);
+        ptr.write(x);
+    }
+    use_it(1);
+}
+
+fn main() {}
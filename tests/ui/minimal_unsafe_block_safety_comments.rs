@@ -0,0 +1,26 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block, clippy::undocumented_unsafe_blocks)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    // SAFETY: `unsafe_fn` has no requirements
+    unsafe {
+        let x = safe_fn(0);
+        unsafe_fn(x);
+    }
+
+    // SAFETY: `unsafe_fn` has no requirements
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+}
+
+#[allow(clippy::undocumented_unsafe_blocks)]
+fn allowed() {
+    let _ = unsafe { safe_fn(unsafe_fn(0)) };
+}
@@ -0,0 +1,38 @@
+//@only-target: x86_64
+#![feature(target_feature_11)]
+#![warn(clippy::minimal_unsafe_block)]
+
+#[target_feature(enable = "avx2")]
+fn simd(x: u32) -> u32 {
+    x
+}
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    // calls to `simd` require `unsafe` here, so they are kept inside the block
+    let _ = unsafe {
+        let x = safe_fn(1);
+        simd(x)
+    };
+    let _ = unsafe { safe_fn(simd(1)) };
+    let _ = unsafe {
+        let x = simd(1);
+        unsafe_fn(x)
+    };
+}
+
+#[target_feature(enable = "avx2")]
+fn with_avx2() -> u32 {
+    // but not in a function with the same target features
+    unsafe {
+        let x = simd(1);
+        unsafe_fn(x)
+    }
+}
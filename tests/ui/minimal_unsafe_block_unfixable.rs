@@ -0,0 +1,122 @@
+//@no-rustfix
+#![warn(clippy::minimal_unsafe_block)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::redundant_nested_unsafe_block,
+    unused
+)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+fn use_it(_: u32) {}
+
+fn let_else(ptr: *const u32) {
+    // the `else` branch performs an unsafe operation itself
+    unsafe {
+        let Some(x) = ptr.as_ref() else {
+            unsafe_fn(0);
+            return;
+        };
+        use_it(*x);
+    }
+}
+
+#[allow(unused_unsafe)]
+fn nested() {
+    // only the outer block is linted
+    unsafe {
+        let x = 1;
+        unsafe {
+            let y = x;
+            unsafe_fn(y);
+        }
+    }
+
+    let _ = unsafe {
+        unsafe {
+            let y = 1;
+            unsafe_fn(y)
+        }
+    };
+}
+
+fn elements() {
+    // the element has more than one unsafe operation
+    let _ = unsafe { [safe_fn(unsafe_fn(0) + unsafe_fn(1)), 2] };
+}
+
+fn conditions() {
+    // the comparison has more than one unsafe operation
+    let _ = unsafe { if unsafe_fn(0) == unsafe_fn(1) { 1 } else { 2 } };
+    // the branches have unsafe operations as well
+    let _ = unsafe { if unsafe_fn(0) == 0 { unsafe_fn(1) } else { 2 } };
+}
+
+fn calls() {
+    // the argument has more than one unsafe operation
+    let _ = unsafe { safe_fn(unsafe_fn(0) + unsafe_fn(1)) };
+}
+
+fn control_flow(ptr: *const u32) -> Option<u32> {
+    let _ = 'a: {
+        unsafe {
+            if safe_fn(0) == 0 {
+                break 'a 0;
+            }
+            unsafe_fn(0)
+        }
+    };
+
+    unsafe {
+        let y = ptr.as_ref()?;
+        use_it(*y);
+    }
+
+    Some(0)
+}
+
+macro_rules! write_reg {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write_volatile($value)
+    };
+}
+
+macro_rules! write_and_log {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write_volatile($value);
+        use_it($value);
+    };
+}
+
+macro_rules! log {
+    ($value:expr) => {
+        use_it($value)
+    };
+}
+
+fn local_macros(ptr: *mut u32) {
+    unsafe {
+        write_reg!(ptr, 1);
+        write_and_log!(ptr, 2);
+    }
+}
+
+macro_rules! twice {
+    ($e:expr) => {
+        ($e, $e)
+    };
+}
+
+fn duplicated() {
+    // the block is expanded twice, but only linted once
+    let _ = twice!(unsafe { safe_fn(unsafe_fn(0)) });
+}
+
+fn main() {}
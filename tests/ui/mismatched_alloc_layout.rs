@@ -0,0 +1,68 @@
+#![warn(clippy::mismatched_alloc_layout)]
+
+use std::alloc::{GlobalAlloc, Layout, System, alloc, alloc_zeroed, dealloc, realloc};
+
+fn free_functions() {
+    unsafe {
+        let ptr = alloc(Layout::new::<u64>());
+        dealloc(ptr, Layout::new::<u32>());
+        //~^ mismatched_alloc_layout
+
+        let layout = Layout::array::<u16>(8).unwrap();
+        let ptr = alloc_zeroed(layout) as *mut u16;
+        dealloc(ptr.cast(), Layout::array::<u16>(4).unwrap());
+        //~^ mismatched_alloc_layout
+
+        let ptr = alloc(Layout::from_size_align(16, 8).unwrap());
+        let _ = realloc(ptr, Layout::from_size_align(16, 4).unwrap(), 32);
+        //~^ mismatched_alloc_layout
+    }
+}
+
+fn global_alloc() {
+    unsafe {
+        let ptr = System.alloc(Layout::new::<[u8; 3]>());
+        System.dealloc(ptr, Layout::new::<[u8; 4]>());
+        //~^ mismatched_alloc_layout
+        let ptr = GlobalAlloc::alloc(&System, Layout::new::<u8>());
+        GlobalAlloc::dealloc(&System, ptr, Layout::new::<u16>());
+        //~^ mismatched_alloc_layout
+    }
+}
+
+fn generic<T, U>(count: usize) {
+    unsafe {
+        let ptr = alloc(Layout::new::<T>());
+        dealloc(ptr, Layout::new::<U>());
+        //~^ mismatched_alloc_layout
+        let ptr = alloc(Layout::array::<T>(count).unwrap());
+        dealloc(ptr, Layout::array::<U>(count).unwrap());
+        //~^ mismatched_alloc_layout
+
+        // Same layouts
+        let ptr = alloc(Layout::new::<T>());
+        dealloc(ptr, Layout::new::<T>());
+        let ptr = alloc(Layout::array::<T>(count).unwrap());
+        dealloc(ptr, Layout::array::<T>(count).unwrap());
+    }
+}
+
+fn same_layouts(size: usize) {
+    unsafe {
+        let layout = Layout::new::<u64>();
+        let ptr = alloc(layout);
+        dealloc(ptr, layout);
+
+        // Different types with the same layout
+        let ptr = alloc(Layout::new::<u32>());
+        dealloc(ptr, Layout::new::<i32>());
+        let ptr = alloc(Layout::new::<[u32; 2]>());
+        dealloc(ptr, Layout::array::<u32>(2).unwrap());
+
+        // Unknown size
+        let ptr = alloc(Layout::from_size_align(size, 8).unwrap());
+        dealloc(ptr, Layout::from_size_align(size * 2, 8).unwrap());
+    }
+}
+
+fn main() {}
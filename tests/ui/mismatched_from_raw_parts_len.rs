@@ -0,0 +1,57 @@
+#![warn(clippy::mismatched_from_raw_parts_len)]
+#![allow(clippy::size_of_in_element_count)]
+
+use std::mem::{size_of, size_of_val};
+use std::ptr::slice_from_raw_parts;
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+fn size_of_len(ptr: *const u8, count: usize) {
+    unsafe {
+        let _ = from_raw_parts(ptr as *const u32, count * size_of::<u64>());
+        //~^ mismatched_from_raw_parts_len
+        let _ = from_raw_parts(ptr.cast::<u16>(), size_of_val(&0u32) * count);
+        //~^ mismatched_from_raw_parts_len
+        let _ = slice_from_raw_parts(ptr as *const u64, count * 2 * size_of::<u32>());
+        //~^ mismatched_from_raw_parts_len
+
+        // A count of bytes
+        let _ = from_raw_parts(ptr, count * size_of::<u64>());
+        let _ = from_raw_parts(ptr as *const i8, count * size_of::<u64>());
+        // Converted to a count of elements
+        let _ = from_raw_parts(ptr as *const u32, count * size_of::<u64>() / size_of::<u32>());
+        // Linted by `size_of_in_element_count`
+        let _ = from_raw_parts(ptr as *const u32, count * size_of::<u32>());
+    }
+}
+
+fn buffer_len(bytes: &[u8], mut words: Vec<u32>, s: &str) {
+    unsafe {
+        let _ = from_raw_parts(bytes.as_ptr() as *const u32, bytes.len());
+        //~^ mismatched_from_raw_parts_len
+        let len = words.len();
+        let _ = from_raw_parts_mut(words.as_mut_ptr().cast::<u64>(), len);
+        //~^ mismatched_from_raw_parts_len
+        let _ = from_raw_parts(s.as_ptr() as *const u16, s.len());
+        //~^ mismatched_from_raw_parts_len
+
+        // Same size
+        let _ = from_raw_parts(words.as_ptr() as *const i32, words.len());
+        let _ = from_raw_parts(bytes.as_ptr(), bytes.len());
+        let _ = from_raw_parts(s.as_ptr() as *const i8, s.len());
+        // Converted to a count of elements
+        let _ = from_raw_parts(bytes.as_ptr() as *const u32, bytes.len() / 4);
+    }
+}
+
+fn generic<T, U>(items: &[T], ptr: *const u8, count: usize) {
+    unsafe {
+        let _ = from_raw_parts(items.as_ptr() as *const U, items.len());
+        //~^ mismatched_from_raw_parts_len
+        let _ = from_raw_parts(ptr as *const U, count * size_of::<T>());
+        //~^ mismatched_from_raw_parts_len
+
+        let _ = from_raw_parts(items.as_ptr(), items.len());
+    }
+}
+
+fn main() {}
@@ -0,0 +1,85 @@
+#![warn(clippy::mostly_safe_unsafe_fn)]
+#![allow(clippy::missing_safety_doc)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+unsafe fn mostly_safe(ptr: *const u32) -> u32 {
+    //~^ mostly_safe_unsafe_fn
+    let a = safe_fn(1);
+    let b = safe_fn(a);
+    let c = safe_fn(b);
+    let d = *ptr;
+    a + b + c + d
+}
+
+unsafe fn no_unsafe_ops(v: &mut Vec<u32>) {
+    //~^ mostly_safe_unsafe_fn
+    v.push(1);
+    v.push(2);
+    v.push(3);
+    v.push(4);
+}
+
+unsafe fn explicit_blocks(ptr: *const u32) -> u32 {
+    //~^ mostly_safe_unsafe_fn
+    let a = safe_fn(1);
+    let b = safe_fn(a);
+    let c = unsafe { ptr.read() };
+    let d = safe_fn(c);
+    a + b + d
+}
+
+/// Reads a value.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads.
+unsafe fn documented(ptr: *const u32) -> u32 {
+    let a = safe_fn(1);
+    let b = safe_fn(a);
+    let c = safe_fn(b);
+    let d = *ptr;
+    a + b + c + d
+}
+
+unsafe fn half_unsafe(ptr: *const u32) -> u32 {
+    let a = unsafe_fn(1);
+    let b = *ptr;
+    let c = safe_fn(b);
+    a + c
+}
+
+unsafe fn short(ptr: *const u32) -> u32 {
+    let a = safe_fn(1);
+    a + *ptr
+}
+
+unsafe trait Trait {
+    unsafe fn f(&self) -> u32;
+}
+
+unsafe impl Trait for u32 {
+    // the trait makes the method `unsafe`
+    unsafe fn f(&self) -> u32 {
+        let a = safe_fn(*self);
+        let b = safe_fn(a);
+        let c = safe_fn(b);
+        a + b + c
+    }
+}
+
+#[allow(clippy::mostly_safe_unsafe_fn)]
+unsafe fn allowed() -> u32 {
+    let a = safe_fn(1);
+    let b = safe_fn(a);
+    let c = safe_fn(b);
+    a + b + c
+}
+
+fn main() {}
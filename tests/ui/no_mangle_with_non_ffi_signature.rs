@@ -0,0 +1,51 @@
+#![warn(clippy::no_mangle_with_non_ffi_signature)]
+#![allow(improper_ctypes_definitions, no_mangle_generic_items)]
+
+pub struct RustConfig {
+    verbose: bool,
+}
+
+#[repr(C)]
+pub struct CConfig {
+    verbose: bool,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn by_reference(config: &CConfig, level: &mut Level) {}
+//~^ no_mangle_with_non_ffi_signature
+
+#[unsafe(no_mangle)]
+pub extern "C" fn returns_reference(config: &CConfig) -> &bool {
+    //~^ no_mangle_with_non_ffi_signature
+    &config.verbose
+}
+
+#[unsafe(export_name = "rust_repr")]
+pub extern "C" fn rust_repr(config: RustConfig) -> u32 {
+    //~^ no_mangle_with_non_ffi_signature
+    0
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn generic<T: Copy, const N: usize>(value: T, f: impl Fn()) {}
+//~^ no_mangle_with_non_ffi_signature
+
+#[unsafe(no_mangle)]
+pub extern "C" fn ffi_compatible(config: *const CConfig, other: *mut RustConfig, level: Level) -> Option<&'static u8> {
+    None
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn nullable(config: Option<&CConfig>) -> i32 {
+    0
+}
+
+pub extern "C" fn not_exported(config: &RustConfig) {}
+
+fn main() {}
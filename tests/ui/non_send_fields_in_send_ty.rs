@@ -1,5 +1,6 @@
 #![warn(clippy::non_send_fields_in_send_ty)]
 #![feature(extern_types)]
+#![allow(clippy::send_sync_impl_without_bounds)]
 
 use std::cell::UnsafeCell;
 use std::ptr::NonNull;
@@ -0,0 +1,58 @@
+#![warn(clippy::panic_in_extern_c_fn)]
+#![allow(improper_ctypes_definitions)]
+
+use std::panic::catch_unwind;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn unwrap(value: u32) -> u8 {
+    //~^ panic_in_extern_c_fn
+    u8::try_from(value).unwrap()
+}
+
+pub extern "C" fn macros(value: u32) {
+    //~^ panic_in_extern_c_fn
+    assert!(value < 10, "out of range");
+}
+
+pub extern "system" fn in_closure(values: &[Option<u32>]) -> u32 {
+    //~^ panic_in_extern_c_fn
+    values.iter().map(|value| value.expect("missing")).sum()
+}
+
+struct Callbacks;
+
+impl Callbacks {
+    extern "C" fn method(&self) {
+        //~^ panic_in_extern_c_fn
+        todo!()
+    }
+}
+
+pub extern "C" fn caught(value: u32) -> u8 {
+    catch_unwind(|| u8::try_from(value).unwrap()).unwrap_or(0)
+}
+
+pub extern "C" fn no_panic(value: u32) -> u8 {
+    u8::try_from(value).unwrap_or(u8::MAX)
+}
+
+pub extern "C-unwind" fn unwinding(value: u32) -> u8 {
+    u8::try_from(value).unwrap()
+}
+
+pub extern "C" fn nested_fn(value: u32) -> u8 {
+    fn inner(value: u32) -> u8 {
+        u8::try_from(value).unwrap()
+    }
+    0
+}
+
+pub fn rust_abi(value: u32) -> u8 {
+    u8::try_from(value).unwrap()
+}
+
+extern "C" {
+    fn declaration(value: u32) -> u8;
+}
+
+fn main() {}
@@ -0,0 +1,96 @@
+#![warn(clippy::partially_initialized_assume_init)]
+
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr::{self, addr_of_mut};
+
+struct Point {
+    x: u32,
+    y: u32,
+    z: u32,
+    marker: PhantomData<u8>,
+}
+
+fn fields() {
+    let mut point = MaybeUninit::<Point>::uninit();
+    let ptr = point.as_mut_ptr();
+    let _ = unsafe {
+        addr_of_mut!((*ptr).x).write(1);
+        point.assume_init()
+        //~^ partially_initialized_assume_init
+    };
+
+    let mut point = MaybeUninit::<Point>::uninit();
+    let ptr = point.as_mut_ptr();
+    unsafe {
+        ptr::write(&raw mut (*ptr).x, 1);
+        (*ptr).z = 3;
+    }
+    let _ = unsafe { point.assume_init_read() };
+    //~^ partially_initialized_assume_init
+}
+
+fn elements() {
+    let mut array = MaybeUninit::<[u16; 4]>::uninit();
+    let ptr = array.as_mut_ptr() as *mut u16;
+    unsafe {
+        ptr.write(1);
+        ptr.add(1).write(2);
+    }
+    let _ = unsafe { array.assume_init() };
+    //~^ partially_initialized_assume_init
+
+    let mut array = MaybeUninit::<[u8; 16]>::uninit();
+    unsafe {
+        (*array.as_mut_ptr())[0] = 1;
+        let _ = array.assume_init();
+        //~^ partially_initialized_assume_init
+    }
+}
+
+fn fully_written() {
+    let mut point = MaybeUninit::<Point>::uninit();
+    let ptr = point.as_mut_ptr();
+    let _ = unsafe {
+        addr_of_mut!((*ptr).x).write(1);
+        addr_of_mut!((*ptr).y).write(2);
+        (*ptr).z = 3;
+        point.assume_init()
+    };
+
+    let mut array = MaybeUninit::<[u16; 2]>::uninit();
+    let ptr = array.as_mut_ptr().cast::<u16>();
+    unsafe {
+        ptr.write(1);
+        ptr.add(1).write(2);
+    }
+    let _ = unsafe { array.assume_init() };
+
+    let mut array = MaybeUninit::<[u16; 2]>::uninit();
+    unsafe {
+        (*array.as_mut_ptr())[0] = 1;
+        array.as_mut_ptr().write([1, 2]);
+        let _ = array.assume_init();
+    }
+}
+
+fn unknown_writes(len: usize, write: impl Fn(*mut u16)) {
+    let mut array = MaybeUninit::<[u16; 4]>::uninit();
+    let ptr = array.as_mut_ptr() as *mut u16;
+    for i in 0..len {
+        unsafe { ptr.add(i).write(0) };
+    }
+    let _ = unsafe { array.assume_init() };
+
+    let mut array = MaybeUninit::<[u16; 4]>::uninit();
+    let ptr = array.as_mut_ptr() as *mut u16;
+    unsafe { ptr.write(1) };
+    write(ptr);
+    let _ = unsafe { array.assume_init() };
+
+    // Nothing is written, linted by `uninit_assumed_init` for some types
+    let point = MaybeUninit::<Point>::uninit();
+    let _ = unsafe { point.assume_init() };
+}
+
+fn main() {}
@@ -0,0 +1,80 @@
+#![warn(clippy::pinned_local_moved)]
+
+use std::marker::PhantomPinned;
+use std::pin::{Pin, pin};
+
+#[derive(Default)]
+struct SelfRef {
+    ptr: Option<*const u8>,
+    _pinned: PhantomPinned,
+}
+
+impl SelfRef {
+    fn init(self: Pin<&mut Self>) {}
+    fn reset(&mut self) {}
+}
+
+fn moved() -> SelfRef {
+    let mut value = SelfRef::default();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+    //~^ pinned_local_moved
+    let _moved = value;
+
+    let mut value = SelfRef::default();
+    let pinned = unsafe { Pin::new_unchecked(&mut value) };
+    //~^ pinned_local_moved
+    pinned.init();
+    value
+}
+
+fn borrowed_mutably() {
+    let mut value = SelfRef::default();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+    //~^ pinned_local_moved
+    value.reset();
+
+    let mut value = SelfRef::default();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+    //~^ pinned_local_moved
+    std::mem::swap(&mut value, &mut SelfRef::default());
+}
+
+fn moved_into_closure() {
+    let mut future = async {};
+    let _pinned = unsafe { Pin::new_unchecked(&mut future) };
+    //~^ pinned_local_moved
+    let _ = move || drop(future);
+}
+
+fn fine() {
+    let mut value = SelfRef::default();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+    let _ = value.ptr;
+    let _ = &value;
+
+    // Pinned again
+    let mut value = SelfRef::default();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+
+    // Moved before
+    let mut value = SelfRef::default();
+    value.reset();
+    unsafe { Pin::new_unchecked(&mut value) }.init();
+
+    // Shadowed by the pin
+    let mut value = SelfRef::default();
+    let value = unsafe { Pin::new_unchecked(&mut value) };
+    value.init();
+
+    // `Unpin`
+    let mut value = 1;
+    let _ = unsafe { Pin::new_unchecked(&mut value) };
+    value += 1;
+    let _moved = value;
+
+    let value = pin!(SelfRef::default());
+    value.init();
+}
+
+fn main() {}
@@ -0,0 +1,94 @@
+#![warn(clippy::ptr_to_int_in_const_fn)]
+#![allow(
+    clippy::missing_transmute_annotations,
+    clippy::transmute_float_to_int,
+    clippy::useless_transmute
+)]
+
+use std::mem::transmute;
+
+union PtrOrInt {
+    ptr: *const u8,
+    int: usize,
+}
+
+const fn addr<T>(value: &T) -> usize {
+    unsafe { transmute(value) }
+    //~^ ptr_to_int_in_const_fn
+}
+
+const fn bytes_addr(ptr: *const u8) -> [u8; 8] {
+    unsafe { transmute(ptr) }
+    //~^ ptr_to_int_in_const_fn
+}
+
+// Linted by rustc
+#[allow(ptr_to_integer_transmute_in_consts)]
+const fn raw_addr(ptr: *const u8) -> usize {
+    unsafe { transmute::<*const u8, usize>(ptr) }
+}
+
+const fn fn_addr(f: fn()) -> [u8; 8] {
+    unsafe { transmute(f) }
+    //~^ ptr_to_int_in_const_fn
+}
+
+const fn union_addr(ptr: *const u8) -> usize {
+    let value = PtrOrInt { ptr };
+    unsafe { value.int }
+    //~^ ptr_to_int_in_const_fn
+}
+
+const fn union_assigned_addr(ptr: *const u8) -> usize {
+    let mut value = PtrOrInt { int: 0 };
+    value.ptr = ptr;
+    unsafe { value.int }
+    //~^ ptr_to_int_in_const_fn
+}
+
+// Not a `const fn`
+fn runtime_addr<T>(value: &T) -> usize {
+    unsafe { transmute(value) }
+}
+
+const fn int_to_ptr(addr: usize) -> *const u8 {
+    unsafe { transmute(addr) }
+}
+
+const fn union_write(ptr: *const u8) -> *const u8 {
+    let mut value = PtrOrInt { ptr };
+    value.int = 0;
+    unsafe { value.ptr }
+}
+
+// The active field isn't known
+const fn union_read(value: PtrOrInt) -> usize {
+    unsafe { value.int }
+}
+
+const fn union_int(addr: usize) -> usize {
+    let value = PtrOrInt { int: addr };
+    unsafe { value.int }
+}
+
+const fn bits(value: f32) -> u32 {
+    unsafe { transmute(value) }
+}
+
+fn main() {
+    let x = 1;
+    let _ = (
+        addr(&x),
+        bytes_addr(&0),
+        raw_addr(&0),
+        fn_addr(main),
+        union_addr(&0),
+        union_assigned_addr(&0),
+        runtime_addr(&x),
+        int_to_ptr(0),
+        union_write(&0),
+        union_read(PtrOrInt { int: 0 }),
+        union_int(0),
+        bits(1.0),
+    );
+}
@@ -0,0 +1,97 @@
+#![warn(clippy::raw_pointer_in_public_api)]
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::not_unsafe_ptr_arg_deref,
+    improper_ctypes_definitions
+)]
+
+use std::ptr::NonNull;
+
+pub fn read(ptr: *const u32) -> u32 {
+    //~^ raw_pointer_in_public_api
+    unsafe { *ptr }
+}
+
+pub fn alloc() -> *mut u8 {
+    //~^ raw_pointer_in_public_api
+    std::ptr::null_mut()
+}
+
+pub unsafe fn copy(src: *const u8, dst: *mut u8, len: usize) {
+    //~^ raw_pointer_in_public_api
+    unsafe { std::ptr::copy(src, dst, len) };
+}
+
+pub fn nested(_: Option<*const u8>, _: &[*mut u8]) {}
+//~^ raw_pointer_in_public_api
+
+pub struct Buffer(Vec<u8>);
+
+impl Buffer {
+    pub fn data(&self) -> *const u8 {
+        //~^ raw_pointer_in_public_api
+        self.0.as_ptr()
+    }
+
+    // Raw pointer conversions
+    pub fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr()
+    }
+
+    pub fn into_raw(self) -> *mut Vec<u8> {
+        Box::into_raw(Box::new(self.0))
+    }
+
+    pub unsafe fn from_raw(ptr: *mut Vec<u8>) -> Self {
+        Self(*unsafe { Box::from_raw(ptr) })
+    }
+
+    fn private(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+}
+
+pub trait Raw {
+    fn raw(&self) -> *const u8;
+    //~^ raw_pointer_in_public_api
+}
+
+trait PrivateRaw {
+    fn raw(&self) -> *const u8;
+}
+
+impl Raw for Buffer {
+    fn raw(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+}
+
+pub mod ffi {
+    #[no_mangle]
+    pub extern "C" fn exported(ptr: *const u8) -> u8 {
+        unsafe { *ptr }
+    }
+}
+
+mod private {
+    pub fn read(ptr: *const u32) -> u32 {
+        unsafe { *ptr }
+    }
+}
+
+pub(crate) fn crate_visible(ptr: *const u32) -> u32 {
+    unsafe { *ptr }
+}
+
+pub fn safe(value: &u32, ptr: NonNull<u32>, values: &[u32]) -> u32 {
+    *value + unsafe { *ptr.as_ptr() } + values.iter().sum::<u32>()
+}
+
+fn main() {
+    let b = Buffer(vec![1]);
+    let _ = (b.private(), private::read(&1), crate_visible(&1));
+}
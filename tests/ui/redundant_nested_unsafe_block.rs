@@ -0,0 +1,74 @@
+#![warn(clippy::redundant_nested_unsafe_block)]
+#![allow(unused, clippy::minimal_unsafe_block)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+macro_rules! call_unsafe {
+    ($x:expr) => {
+        unsafe { unsafe_fn($x) }
+    };
+}
+
+fn tail_only(ptr: *const u32) {
+    unsafe {
+        let x = ptr.read();
+        let y = unsafe { unsafe_fn(x) };
+        //~^ redundant_nested_unsafe_block
+        let z = unsafe { unsafe_fn(x) + 1 } * 2;
+        //~^ redundant_nested_unsafe_block
+        safe_fn(unsafe { unsafe_fn(y) + z });
+        //~^ redundant_nested_unsafe_block
+    }
+}
+
+fn with_stmts(ptr: *mut u32) {
+    unsafe {
+        ptr.write(1);
+        unsafe {
+            //~^ redundant_nested_unsafe_block
+            let x = unsafe_fn(1);
+            ptr.write(x);
+        }
+    }
+}
+
+fn only_wrapper() -> u32 {
+    unsafe {
+        unsafe {
+            //~^ redundant_nested_unsafe_block
+            let x = unsafe_fn(1);
+            unsafe_fn(x)
+        }
+    }
+}
+
+fn closures() {
+    unsafe {
+        let f = || unsafe { unsafe_fn(1) };
+        //~^ redundant_nested_unsafe_block
+    }
+}
+
+fn not_nested() {
+    let x = unsafe { unsafe_fn(1) };
+    let y = unsafe { unsafe_fn(x) };
+
+    // the inner block is part of the macro
+    unsafe {
+        unsafe_fn(0);
+        call_unsafe!(1);
+    }
+}
+
+unsafe fn in_unsafe_fn() -> u32 {
+    // an `unsafe` function body isn't an `unsafe` block
+    unsafe { unsafe_fn(1) }
+}
+
+fn main() {}
@@ -0,0 +1,71 @@
+#![warn(clippy::send_sync_impl_without_bounds)]
+#![allow(clippy::non_send_fields_in_send_ty)]
+
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+
+struct Wrapper<T>(*mut T);
+
+unsafe impl<T> Send for Wrapper<T> {}
+//~^ send_sync_impl_without_bounds
+
+unsafe impl<T> Sync for Wrapper<T> {}
+//~^ send_sync_impl_without_bounds
+
+struct Pair<A, B> {
+    first: A,
+    second: Vec<B>,
+}
+
+unsafe impl<A: Send, B> Send for Pair<A, B> {}
+//~^ send_sync_impl_without_bounds
+
+struct Both<A, B>(*mut A, *mut B);
+
+unsafe impl<A, B> Send for Both<A, B> {}
+//~^ send_sync_impl_without_bounds
+
+// A bound on the other trait doesn't help
+struct OtherBound<T>(*mut T);
+
+unsafe impl<T: Sync> Send for OtherBound<T> {}
+//~^ send_sync_impl_without_bounds
+
+// Like `Mutex`, sharing only requires `T: Send`, but this has to be checked manually
+struct MyMutex<T>(UnsafeCell<T>);
+
+unsafe impl<T: Send> Sync for MyMutex<T> {}
+//~^ send_sync_impl_without_bounds
+
+struct Marker<T> {
+    ptr: *const u8,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T> Send for Marker<T> {}
+//~^ send_sync_impl_without_bounds
+
+// Bounded type parameters
+struct Bounded<T>(*mut T);
+
+unsafe impl<T: Send> Send for Bounded<T> {}
+unsafe impl<T> Sync for Bounded<T> where T: Sync {}
+
+// The type parameter isn't stored
+struct NotStored<T> {
+    ptr: *const u8,
+    _marker: PhantomData<fn() -> T>,
+}
+
+unsafe impl<T> Send for NotStored<T> {}
+
+// Concrete types
+struct Concrete(*mut u8);
+
+unsafe impl Send for Concrete {}
+
+struct Generic<T>(*mut T);
+
+unsafe impl Send for Generic<u8> {}
+
+fn main() {}
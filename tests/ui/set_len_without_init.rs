@@ -0,0 +1,78 @@
+#![warn(clippy::set_len_without_init)]
+#![allow(clippy::uninit_vec)]
+
+use std::mem::MaybeUninit;
+use std::ptr;
+
+fn extend(vec: &mut Vec<String>, additional: usize) {
+    vec.reserve(additional);
+    println!("reserved");
+    unsafe { vec.set_len(vec.len() + additional) };
+    //~^ set_len_without_init
+}
+
+fn param(mut vec: Vec<u32>, len: usize) -> Vec<u32> {
+    unsafe {
+        vec.set_len(len);
+        //~^ set_len_without_init
+    }
+    vec
+}
+
+struct S {
+    buf: Vec<u8>,
+}
+
+impl S {
+    fn grow(&mut self, n: usize) {
+        unsafe { self.buf.set_len(n) };
+        //~^ set_len_without_init
+    }
+
+    fn fill(&mut self, n: usize) {
+        let ptr = self.buf.as_mut_ptr();
+        unsafe {
+            ptr.write_bytes(0, n);
+            self.buf.set_len(n);
+        }
+    }
+}
+
+fn spare_capacity(vec: &mut Vec<u8>, n: usize) {
+    for slot in vec.spare_capacity_mut().iter_mut().take(n) {
+        slot.write(0);
+    }
+    unsafe { vec.set_len(n) };
+}
+
+fn copied(vec: &mut Vec<u8>, src: &[u8], dst: *mut u8) {
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len());
+        vec.set_len(src.len());
+    }
+}
+
+fn shrinking(vec: &mut Vec<String>) {
+    unsafe {
+        vec.set_len(0);
+        vec.set_len(vec.len() - 1);
+    }
+}
+
+fn uninit_elements(vec: &mut Vec<MaybeUninit<String>>, n: usize) {
+    unsafe { vec.set_len(n) };
+}
+
+fn reserved(n: usize) {
+    // linted by `uninit_vec`
+    let mut vec: Vec<String> = Vec::with_capacity(n);
+    unsafe { vec.set_len(n) };
+}
+
+fn init_after(vec: &mut Vec<u8>, n: usize) {
+    unsafe { vec.set_len(n) };
+    //~^ set_len_without_init
+    let _ = vec.as_mut_ptr();
+}
+
+fn main() {}
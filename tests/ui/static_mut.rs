@@ -0,0 +1,55 @@
+//@aux-build:static_mut_helper.rs
+#![warn(clippy::static_mut)]
+#![allow(dead_code)]
+
+extern crate static_mut_helper;
+
+use static_mut_helper::{EXTERNAL_COUNTER, EXTERNAL_IMMUTABLE};
+
+static mut FLAG: bool = false;
+//~^ static_mut
+
+static mut COUNTER: usize = 0;
+//~^ static_mut
+
+static mut SIGNED: i64 = 0;
+//~^ static_mut
+
+static mut PTR: *mut u8 = std::ptr::null_mut();
+//~^ static_mut
+
+static mut CONFIG: Option<String> = None;
+//~^ static_mut
+
+static mut BUFFER: Vec<u8> = Vec::new();
+//~^ static_mut
+
+static mut WIDE: u128 = 0;
+//~^ static_mut
+
+static IMMUTABLE: usize = 0;
+
+fn f() {
+    static mut LOCAL: u32 = 0;
+    //~^ static_mut
+}
+
+extern "C" {
+    static mut FOREIGN_COUNTER: u32;
+    //~^ static_mut
+    static mut FOREIGN_CONFIG: [u8; 16];
+    //~^ static_mut
+    static FOREIGN_IMMUTABLE: u32;
+}
+
+fn main() {
+    unsafe {
+        EXTERNAL_COUNTER += 1;
+        //~^ static_mut
+        let _ = std::ptr::addr_of!(static_mut_helper::EXTERNAL_COUNTER);
+        //~^ static_mut
+        let _ = EXTERNAL_IMMUTABLE;
+        // Linted at the definition
+        COUNTER += 1;
+    }
+}
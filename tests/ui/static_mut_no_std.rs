@@ -0,0 +1,9 @@
+#![crate_type = "lib"]
+#![no_std]
+#![warn(clippy::static_mut)]
+
+static mut COUNTER: usize = 0;
+//~^ static_mut
+
+static mut BUFFER: [u8; 16] = [0; 16];
+//~^ static_mut
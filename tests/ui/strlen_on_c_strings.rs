@@ -1,5 +1,5 @@
 #![warn(clippy::strlen_on_c_strings)]
-#![allow(dead_code, clippy::manual_c_str_literals)]
+#![allow(dead_code, clippy::redundant_nested_unsafe_block, clippy::manual_c_str_literals)]
 #![feature(rustc_private)]
 extern crate libc;
 
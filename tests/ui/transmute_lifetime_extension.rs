@@ -0,0 +1,70 @@
+#![warn(clippy::transmute_lifetime_extension)]
+#![allow(clippy::missing_transmute_annotations, clippy::needless_lifetimes, dead_code)]
+
+use std::mem::transmute;
+
+type Static<T> = &'static T;
+
+struct Holder<'a>(&'a str);
+
+fn turbofish<'a>(x: &'a str, y: &'a mut Vec<u8>) {
+    let _ = unsafe { transmute::<&'a str, &'static str>(x) };
+    //~^ transmute_lifetime_extension
+    let _ = unsafe { transmute::<&mut Vec<u8>, &'static mut Vec<u8>>(y) };
+    //~^ transmute_lifetime_extension
+}
+
+fn elided_turbofish<'a>(x: &'a u32) -> &'static u32 {
+    unsafe { transmute::<&u32, &u32>(x) }
+    //~^ transmute_lifetime_extension
+}
+
+fn elided_param(x: &u32) -> &'static u32 {
+    unsafe { transmute::<&u32, &'static u32>(x) }
+    //~^ transmute_lifetime_extension
+}
+
+fn elided_source(names: &[String]) -> &'static str {
+    unsafe { transmute::<&str, &'static str>(names[0].as_str()) }
+    //~^ transmute_lifetime_extension
+}
+
+fn annotated<'a>(x: &'a [u8]) {
+    let _: Static<[u8]> = unsafe { transmute(x) };
+    //~^ transmute_lifetime_extension
+    let local: &'a [u8] = x;
+    let _: &'static [u8] = unsafe { transmute(local) };
+    //~^ transmute_lifetime_extension
+}
+
+fn returned<'a>(h: Holder<'a>) -> Holder<'static> {
+    unsafe { transmute(h) }
+    //~^ transmute_lifetime_extension
+}
+
+fn between<'a, 'b>(x: &'a u32) -> &'b u32 {
+    unsafe { transmute(x) }
+    //~^ transmute_lifetime_extension
+}
+
+fn unknown_source(x: &u32) {
+    let y = x;
+    let _: &'static u32 = unsafe { transmute(y) };
+    //~^ transmute_lifetime_extension
+}
+
+fn not_lifetimes_only(x: &u32) {
+    let _: &'static i32 = unsafe { transmute(x) };
+    let _ = unsafe { transmute::<&u32, &[u8; 4]>(x) };
+}
+
+fn same_lifetime<'a>(x: &'a u32) -> &'a u32 {
+    unsafe { transmute(x) }
+}
+
+fn unknown_lifetimes(x: &u32) {
+    let y = x;
+    let _: &u32 = unsafe { transmute(y) };
+}
+
+fn main() {}
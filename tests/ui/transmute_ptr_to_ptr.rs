@@ -7,11 +7,13 @@ use std::mem::transmute;
 // of transmute
 
 // Make sure we can do static lifetime transmutes
+#[allow(clippy::transmute_lifetime_extension)]
 unsafe fn transmute_lifetime_to_static<'a, T>(t: &'a T) -> &'static T {
     transmute::<&'a T, &'static T>(t)
 }
 
 // Make sure we can do non-static lifetime transmutes
+#[allow(clippy::transmute_lifetime_extension)]
 unsafe fn transmute_lifetime<'a, 'b, T>(t: &'a T, u: &'b T) -> &'b T {
     transmute::<&'a T, &'b T>(t)
 }
@@ -1,7 +1,11 @@
 //@no-rustfix
 
 #![deny(clippy::transmute_ptr_to_ptr)]
-#![allow(dead_code, clippy::missing_transmute_annotations)]
+#![allow(
+    dead_code,
+    clippy::redundant_nested_unsafe_block,
+    clippy::missing_transmute_annotations
+)]
 
 fn main() {
     unsafe {
@@ -0,0 +1,74 @@
+#![warn(clippy::undocumented_safe_wrapper)]
+#![allow(clippy::missing_safety_doc, clippy::missing_inline_in_public_items, dead_code)]
+
+unsafe fn inner(x: usize) -> usize {
+    x
+}
+
+pub struct S;
+
+impl S {
+    unsafe fn get_unchecked(&self, x: usize) -> usize {
+        x
+    }
+
+    //~v undocumented_safe_wrapper
+    pub fn get(&self, x: usize) -> usize {
+        unsafe { self.get_unchecked(x) }
+    }
+}
+
+//~v undocumented_safe_wrapper
+pub fn wrapper(x: usize) -> usize {
+    unsafe { inner(x) }
+}
+
+//~v undocumented_safe_wrapper
+pub fn wrapper_stmt(x: usize) {
+    unsafe {
+        inner(x);
+    };
+}
+
+pub fn commented(x: usize) -> usize {
+    // SAFETY: `inner` accepts any value
+    unsafe { inner(x) }
+}
+
+/// Wraps `inner`.
+///
+/// # Safety
+///
+/// `inner` accepts any value.
+pub fn documented(x: usize) -> usize {
+    unsafe { inner(x) }
+}
+
+fn private(x: usize) -> usize {
+    unsafe { inner(x) }
+}
+
+pub unsafe fn unsafe_wrapper(x: usize) -> usize {
+    unsafe { inner(x) }
+}
+
+pub fn checked(x: usize) -> usize {
+    assert!(x < 10);
+    unsafe { inner(x) }
+}
+
+pub fn safe_call(x: usize) -> usize {
+    unsafe { x.wrapping_add(1) }
+}
+
+pub trait Get {
+    fn get(&self, x: usize) -> usize;
+}
+
+impl Get for usize {
+    fn get(&self, x: usize) -> usize {
+        unsafe { inner(x) }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+#![warn(clippy::undocumented_union_read)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+union IntOrFloat {
+    int: u32,
+    float: f32,
+}
+
+fn bad(value: IntOrFloat, other: &IntOrFloat) -> f32 {
+    let int = unsafe { value.int };
+    //~^ undocumented_union_read
+
+    // SAFETY: `float` was written by the caller.
+    unsafe {
+        let float = other.float;
+        //~^ undocumented_union_read
+        let int = other.int;
+        //~^ undocumented_union_read
+        float + int as f32
+    }
+}
+
+fn good(mut value: IntOrFloat, other: &IntOrFloat) -> f32 {
+    // SAFETY: `int` is the active field.
+    let int = unsafe { value.int };
+
+    // SAFETY: `float` is the active field.
+    unsafe { other.float };
+
+    unsafe {
+        // SAFETY: `float` was written by the caller.
+        let float = other.float;
+        // SAFETY: the bytes of any `f32` are a valid `u32`.
+        let bits = other.int;
+    }
+
+    // Writes are safe
+    value.float = 1.0;
+
+    // Places which aren't read
+    let _ = unsafe { std::ptr::addr_of!(value.int) };
+    let _ = unsafe { std::ptr::addr_of_mut!(value.float) };
+    let _ = unsafe { &raw const other.int };
+    unsafe {
+        let _ = value.int;
+    }
+
+    int as f32
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::undocumented_unsafe_impls)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+
+struct Buffer(*mut u8);
+
+unsafe impl Send for Buffer {}
+//~^ undocumented_unsafe_impls
+
+unsafe impl Sync for Buffer {}
+//~^ undocumented_unsafe_impls
+
+struct Alloc;
+
+unsafe impl GlobalAlloc for Alloc {
+    //~^ undocumented_unsafe_impls
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: the requirements are the same as for `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the requirements are the same as for `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+struct Documented(*mut u8);
+
+// SAFETY: `Documented` owns the allocation it points to.
+unsafe impl Send for Documented {}
+
+/// # Safety
+///
+/// `Documented` never gives out references to the allocation.
+unsafe impl Sync for Documented {}
+
+mod blocks {
+    #![warn(clippy::undocumented_unsafe_blocks)]
+
+    struct Buffer(*mut u8);
+
+    // Only `undocumented_unsafe_blocks` lints here
+    unsafe impl Send for Buffer {}
+    //~^ undocumented_unsafe_blocks
+}
+
+fn main() {}
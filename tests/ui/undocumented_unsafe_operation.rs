@@ -0,0 +1,88 @@
+#![warn(clippy::undocumented_unsafe_operation)]
+#![allow(clippy::missing_safety_doc)]
+
+unsafe fn unsafe_fn(x: u32) -> u32 {
+    x
+}
+
+fn safe_fn(x: u32) -> u32 {
+    x
+}
+
+macro_rules! write_twice {
+    ($ptr:expr, $value:expr) => {
+        $ptr.write($value);
+        $ptr.write($value);
+    };
+}
+
+fn documented(ptr: *mut u32) {
+    // SAFETY: the block's comment documents the first operation
+    unsafe {
+        ptr.write(1);
+        // SAFETY: every other operation has its own comment
+        ptr.write(2);
+        safe_fn(3);
+        // SAFETY: the tail expression is documented as well
+        unsafe_fn(4)
+    };
+
+    unsafe {
+        // SAFETY: without a comment on the block, the first operation needs its own
+        ptr.write(1);
+        // SAFETY: ..
+        ptr.write(2);
+    }
+}
+
+fn undocumented(ptr: *mut u32) {
+    // SAFETY: only the first operation is documented
+    unsafe {
+        ptr.write(1);
+        ptr.write(2);
+        //~^ undocumented_unsafe_operation
+        let _ = safe_fn(3);
+        unsafe_fn(4);
+        //~^ undocumented_unsafe_operation
+    }
+
+    unsafe {
+        ptr.write(1);
+        //~^ undocumented_unsafe_operation
+        // SAFETY: ..
+        ptr.write(2);
+        let x = unsafe_fn(
+            //~^ undocumented_unsafe_operation
+            3,
+        );
+        unsafe_fn(x)
+        //~^ undocumented_unsafe_operation
+    };
+}
+
+fn single_operation(ptr: *mut u32) {
+    // a single unsafe operation is left to `undocumented_unsafe_blocks`
+    unsafe {
+        let x = safe_fn(1);
+        ptr.write(x);
+    }
+}
+
+fn macros(ptr: *mut u32) {
+    // SAFETY: the statements of a macro call share one comment
+    unsafe {
+        write_twice!(ptr, 1);
+        // SAFETY: ..
+        ptr.write(2);
+    }
+}
+
+#[allow(clippy::undocumented_unsafe_operation)]
+fn allowed(ptr: *mut u32) {
+    unsafe {
+        ptr.write(1);
+        ptr.write(2);
+    }
+}
+
+fn main() {}
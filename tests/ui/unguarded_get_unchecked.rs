@@ -0,0 +1,104 @@
+#![warn(clippy::unguarded_get_unchecked)]
+#![allow(clippy::missing_safety_doc, clippy::needless_range_loop, dead_code)]
+
+fn unguarded(v: &[u8], s: &str, i: usize) {
+    let _ = unsafe { v.get_unchecked(i) };
+    //~^ unguarded_get_unchecked
+
+    let _ = unsafe { s.get_unchecked(i..) };
+    //~^ unguarded_get_unchecked
+
+    let mut vec: Vec<u8> = Vec::new();
+    let _ = unsafe { vec.get_unchecked_mut(i + 1) };
+    //~^ unguarded_get_unchecked
+
+    let _ = unsafe { v.get_unchecked(0) };
+    //~^ unguarded_get_unchecked
+}
+
+fn checked_after(v: &[u8], i: usize) {
+    let _ = unsafe { v.get_unchecked(i) };
+    //~^ unguarded_get_unchecked
+    assert!(i < v.len());
+}
+
+fn unrelated_checks(v: &[u8], w: &[u8], i: usize) -> u8 {
+    if i > 100 {
+        return 0;
+    }
+    let mut sum = unsafe { *v.get_unchecked(i) };
+    //~^ unguarded_get_unchecked
+    for i in 0..w.len() {
+        sum += unsafe { *v.get_unchecked(i) };
+        //~^ unguarded_get_unchecked
+    }
+    sum
+}
+
+fn indexed(v: &[u8], w: &[u8], i: usize) -> u8 {
+    let x = w[i];
+    x + unsafe { *v.get_unchecked(i) }
+    //~^ unguarded_get_unchecked
+}
+
+fn range(s: &str, i: usize, j: usize) -> &str {
+    assert!(j <= s.len());
+    let _ = unsafe { s.get_unchecked(..i) };
+    //~^ unguarded_get_unchecked
+    unsafe { s.get_unchecked(..j) }
+}
+
+fn asserted(v: &[u8], i: usize) {
+    debug_assert!(i < v.len());
+    let _ = unsafe { v.get_unchecked(i) };
+}
+
+fn compared(v: &[u8], i: usize) -> u8 {
+    if i >= v.len() {
+        return 0;
+    }
+    unsafe { *v.get_unchecked(i) }
+}
+
+fn compared_expr(v: &[u8], i: usize) -> u8 {
+    if i + 1 < v.len() {
+        unsafe { *v.get_unchecked(i + 1) }
+    } else {
+        0
+    }
+}
+
+fn indexed_receiver(v: &[u8], i: usize) -> u8 {
+    let x = v[i];
+    x + unsafe { *v.get_unchecked(i) }
+}
+
+fn looped(v: &[u8]) -> u8 {
+    let mut sum = 0;
+    for i in 0..v.len() {
+        sum += unsafe { *v.get_unchecked(i) };
+    }
+    for (i, _) in v.iter().enumerate() {
+        sum += unsafe { *v.get_unchecked(i) };
+    }
+    sum
+}
+
+fn constant(v: &[u8]) -> u8 {
+    if v.is_empty() {
+        return 0;
+    }
+    unsafe { *v.get_unchecked(0) }
+}
+
+fn closure(v: &[u8], i: usize) {
+    assert!(i < v.len());
+    let f = || unsafe { *v.get_unchecked(i) };
+}
+
+unsafe fn raw_index(v: &[u8], i: usize) -> u8 {
+    // not a slice method
+    *v.as_ptr().add(i)
+}
+
+fn main() {}
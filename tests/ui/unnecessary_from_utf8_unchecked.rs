@@ -0,0 +1,16 @@
+#![warn(clippy::unnecessary_from_utf8_unchecked)]
+
+use std::str;
+
+fn main() {
+    let _ = unsafe { str::from_utf8_unchecked(b"hello") };
+    //~^ unnecessary_from_utf8_unchecked
+    let _ = unsafe { std::str::from_utf8_unchecked(br"C:\dir") };
+    //~^ unnecessary_from_utf8_unchecked
+    let _ = unsafe { str::from_utf8_unchecked(b"\xC3\xA9t\xC3\xA9") };
+    //~^ unnecessary_from_utf8_unchecked
+
+    // Unknown bytes
+    let bytes = std::env::args().next().unwrap().into_bytes();
+    let _ = unsafe { str::from_utf8_unchecked(&bytes) };
+}
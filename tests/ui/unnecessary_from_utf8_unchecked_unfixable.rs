@@ -0,0 +1,19 @@
+//@no-rustfix
+#![warn(clippy::unnecessary_from_utf8_unchecked)]
+
+use std::str;
+
+const HELLO: &[u8] = b"hello";
+const BYTES: [u8; 2] = [104, 105];
+const INVALID: &[u8] = b"\xFF";
+
+fn main() {
+    let _ = unsafe { str::from_utf8_unchecked(HELLO) };
+    //~^ unnecessary_from_utf8_unchecked
+    let _ = unsafe { str::from_utf8_unchecked(&BYTES) };
+    //~^ unnecessary_from_utf8_unchecked
+
+    // Invalid bytes
+    #[allow(invalid_from_utf8_unchecked)]
+    let _ = unsafe { str::from_utf8_unchecked(INVALID) };
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::unnecessary_unchecked_constructor)]
+
+use std::num::{NonZero, NonZeroU32};
+
+const ONE_NZ: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(1) };
+
+fn main() {
+    let _ = unsafe { NonZeroU32::new_unchecked(1) };
+    //~^ unnecessary_unchecked_constructor
+    let _ = unsafe { NonZero::<i8>::new_unchecked(-1) };
+    //~^ unnecessary_unchecked_constructor
+    let _ = unsafe { char::from_u32_unchecked(0x61) };
+    //~^ unnecessary_unchecked_constructor
+    let _ = unsafe { std::char::from_u32_unchecked(0x301) };
+    //~^ unnecessary_unchecked_constructor
+    let _ = unsafe { char::from_u32_unchecked(0xA) };
+    //~^ unnecessary_unchecked_constructor
+
+    // The `unsafe` block contains more than the call
+    let n = std::env::args().count() as u32;
+    let _ = unsafe {
+        let _ = char::from_u32_unchecked(n);
+        char::from_u32_unchecked(0x41)
+        //~^ unnecessary_unchecked_constructor
+    };
+}
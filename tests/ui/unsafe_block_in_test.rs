@@ -0,0 +1,36 @@
+#![warn(clippy::unsafe_block_in_test)]
+
+unsafe fn unsafe_fn() -> u32 {
+    1
+}
+
+fn not_a_test() -> u32 {
+    unsafe { unsafe_fn() }
+}
+
+#[test]
+fn test() {
+    assert_eq!(unsafe { unsafe_fn() }, 1);
+    //~^ unsafe_block_in_test
+
+    let f = || unsafe { unsafe_fn() };
+    //~^ unsafe_block_in_test
+    assert_eq!(f(), not_a_test());
+}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    fn helper() -> u32 {
+        unsafe { super::unsafe_fn() }
+    }
+
+    #[test]
+    fn test() {
+        unsafe {
+            //~^ unsafe_block_in_test
+            assert_eq!(super::unsafe_fn(), helper());
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![warn(clippy::wildcard_unreachable_unchecked)]
+
+use std::cmp::Ordering;
+use std::hint::unreachable_unchecked;
+use std::io::ErrorKind;
+
+fn foreign(kind: ErrorKind, kind_ref: &ErrorKind) {
+    match kind {
+        ErrorKind::NotFound => {},
+        _ => unsafe { unreachable_unchecked() },
+        //~^ wildcard_unreachable_unchecked
+    }
+
+    match kind_ref {
+        ErrorKind::NotFound | ErrorKind::PermissionDenied => {},
+        other => unsafe {
+            //~^ wildcard_unreachable_unchecked
+            unreachable_unchecked();
+        },
+    }
+
+    // A guard can't reach unknown variants by itself
+    match kind {
+        ErrorKind::NotFound => {},
+        _ if kind == ErrorKind::Other => unsafe { unreachable_unchecked() },
+        _ => {},
+    }
+
+    // Not `unreachable_unchecked`
+    match kind {
+        ErrorKind::NotFound => {},
+        _ => unreachable!(),
+    }
+}
+
+#[non_exhaustive]
+enum Local {
+    A,
+    B,
+}
+
+fn local(local: Local, ordering: Ordering) {
+    // Variants can't be added without changing this crate
+    match local {
+        Local::A => {},
+        _ => unsafe { unreachable_unchecked() },
+    }
+
+    match ordering {
+        Ordering::Less | Ordering::Equal => {},
+        _ => unsafe { unreachable_unchecked() },
+    }
+}
+
+fn main() {}